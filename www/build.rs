@@ -0,0 +1,70 @@
+//! Prepares the SVGs in `../assets` for inlining by the `Icon` component.
+//!
+//! Each `name.svg` becomes `$OUT_DIR/icons/name.svg` (the markup inside the
+//! root `<svg>`, with its presentation attributes moved onto a `<g>`) and
+//! `$OUT_DIR/icons/name.viewbox`. Solid colors in single-color artwork are
+//! replaced with `currentColor` so icons follow the text color; files that
+//! use gradients or other `url(#…)` paints keep their colors. `IconName`
+//! `include_str!`s these files, so a variant without an SVG fails the build.
+//!
+//! It also turns `../assets/generated/manifest.tsv`, written by the
+//! `imagegen` tool, into `$OUT_DIR/images.rs`: the table of responsive image
+//! variants used by the `Image` component. Without a manifest the table is
+//! empty and images are served as-is.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+#[path = "build/svg.rs"]
+mod svg;
+
+fn main() {
+    let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR"));
+    let assets = manifest.join("../assets");
+    let out = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR")).join("icons");
+    println!("cargo:rerun-if-changed={}", assets.display());
+    // Start empty, so an icon whose SVG was deleted or renamed fails to
+    // compile instead of using the copy from an earlier build.
+    let _ = fs::remove_dir_all(&out);
+    fs::create_dir_all(&out).expect("create icons dir");
+
+    let entries = fs::read_dir(&assets).unwrap_or_else(|err| panic!("reading {}: {}", assets.display(), err));
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if path.extension().is_none_or(|ext| ext != "svg") {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", path.display());
+        let stem = path.file_stem().and_then(|stem| stem.to_str()).expect("utf-8 file name");
+        let source = fs::read_to_string(&path).unwrap_or_else(|err| panic!("reading {}: {}", path.display(), err));
+        match svg::normalize(&source) {
+            Some((view_box, body)) => {
+                fs::write(out.join(format!("{stem}.viewbox")), view_box).expect("write viewbox");
+                fs::write(out.join(format!("{stem}.svg")), body).expect("write icon");
+            }
+            None => println!("cargo:warning=skipping {}: no <svg> root", path.display()),
+        }
+    }
+
+    let images = assets.join("generated/manifest.tsv");
+    println!("cargo:rerun-if-changed={}", images.display());
+    let table = image_table(&fs::read_to_string(&images).unwrap_or_default());
+    fs::write(out.with_file_name("images.rs"), table).expect("write image table");
+}
+
+/// Rust source for the `IMAGES` table from the imagegen manifest.
+fn image_table(manifest: &str) -> String {
+    let mut table = String::from("pub static IMAGES: &[ImageAsset] = &[\n");
+    for line in manifest.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [name, width, height, color, fallback, widths, placeholder] = fields[..] else {
+            println!("cargo:warning=skipping malformed image manifest line: {line}");
+            continue;
+        };
+        table.push_str(&format!(
+            "    ImageAsset {{ name: {name:?}, width: {width}, height: {height}, color: {color:?}, fallback: {fallback:?}, widths: &[{widths}], placeholder: {placeholder:?} }},\n"
+        ));
+    }
+    table.push_str("];\n");
+    table
+}
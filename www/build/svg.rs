@@ -0,0 +1,181 @@
+//! SVG clean-up for `build.rs`, kept in its own file so the crate's tests
+//! can include it too.
+
+/// Root attributes carried over to the wrapping `<g>`.
+const PRESENTATION: &[&str] = &["fill", "stroke", "stroke-width", "stroke-linecap", "stroke-linejoin", "fill-rule"];
+
+/// Remove every `open … close` span.
+fn strip_between(source: &str, open: &str, close: &str) -> String {
+    let mut rest = source;
+    let mut result = String::with_capacity(source.len());
+    while let Some(start) = rest.find(open) {
+        result.push_str(&rest[..start]);
+        let after_open = start + open.len();
+        match rest[after_open..].find(close) {
+            Some(end) => rest = &rest[after_open + end + close.len()..],
+            None => {
+                rest = "";
+                break;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// The value of attribute `name` in a tag's attribute string.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let needle = format!("{name}=\"");
+    let mut search = 0;
+    while let Some(found) = attributes[search..].find(&needle) {
+        let start = search + found;
+        // Skip matches inside longer names like `stroke-width` for `width`.
+        let boundary = start == 0 || attributes[..start].ends_with(char::is_whitespace);
+        let value_start = start + needle.len();
+        let value_end = value_start + attributes[value_start..].find('"')?;
+        if boundary {
+            return Some(&attributes[value_start..value_end]);
+        }
+        search = value_end;
+    }
+    None
+}
+
+/// Replace solid `fill`/`stroke` colors with `currentColor`.
+fn recolor(markup: &str) -> String {
+    let mut result = markup.to_string();
+    for prefix in ["fill=\"", "stroke=\"", "fill:", "stroke:"] {
+        let mut output = String::with_capacity(result.len());
+        let mut rest = result.as_str();
+        while let Some(found) = rest.find(prefix) {
+            let value_start = found + prefix.len();
+            output.push_str(&rest[..value_start]);
+            rest = &rest[value_start..];
+            let end = rest.find(['"', ';', '}']).unwrap_or(rest.len());
+            let value = rest[..end].trim();
+            let solid = value.starts_with('#') || value.starts_with("rgb") || value == "black" || value == "white";
+            output.push_str(if solid { "currentColor" } else { &rest[..end] });
+            rest = &rest[end..];
+        }
+        output.push_str(rest);
+        result = output;
+    }
+    result
+}
+
+/// Move `.class { … }` rules from `<style>` blocks onto the elements using
+/// them: inline SVG stylesheets apply to the whole page, not just the icon.
+fn inline_styles(markup: &str) -> String {
+    let mut rules = Vec::new();
+    let mut rest = markup;
+    while let Some(start) = rest.find("<style") {
+        let Some(end) = rest[start..].find("</style>") else {
+            break;
+        };
+        let css = &rest[start..start + end];
+        let css = &css[css.find('>').map_or(css.len(), |open| open + 1)..];
+        for rule in css.split('}') {
+            if let Some((selector, declarations)) = rule.split_once('{') {
+                if let Some(class) = selector.trim().strip_prefix('.') {
+                    rules.push((class.to_string(), declarations.trim().to_string()));
+                }
+            }
+        }
+        rest = &rest[start + end..];
+    }
+
+    let mut body = strip_between(markup, "<style", "</style>");
+    for (class, declarations) in rules {
+        body = body.replace(&format!("class=\"{class}\""), &format!("style=\"{declarations}\""));
+    }
+    body
+}
+
+/// Split an SVG document into its view box and inner markup.
+pub fn normalize(source: &str) -> Option<(String, String)> {
+    let source = strip_between(source, "<!--", "-->");
+    let source = strip_between(&source, "<?", "?>");
+    let source = strip_between(&source, "<!DOCTYPE", ">");
+
+    let open = source.find("<svg")?;
+    let open_end = open + source[open..].find('>')?;
+    let close = source.rfind("</svg>")?;
+    let attributes = &source[open + 4..open_end];
+    let mut body = source[open_end + 1..close].to_string();
+
+    let view_box = attribute(attributes, "viewBox").map(str::to_string).or_else(|| {
+        let number = |name| attribute(attributes, name).map(|value: &str| value.trim_end_matches("px").to_string());
+        Some(format!("0 0 {} {}", number("width")?, number("height")?))
+    })?;
+
+    let presentation: String = PRESENTATION
+        .iter()
+        .filter_map(|name| attribute(attributes, name).map(|value| format!(" {name}=\"{value}\"")))
+        .collect();
+    if !presentation.is_empty() {
+        body = format!("<g{presentation}>{body}</g>");
+    }
+
+    // The component renders its own accessible title.
+    body = strip_between(&body, "<title>", "</title>");
+    body = inline_styles(&body);
+
+    // Artwork painted with gradients or patterns keeps its colors, and its
+    // ids, which those paints refer to.
+    if !body.contains("url(#") {
+        body = recolor(&body);
+        body = strip_between(&body, " id=\"", "\"");
+    }
+
+    let body = body.lines().map(str::trim).filter(|line| !line.is_empty() && *line != "<g/>").collect::<Vec<_>>().join("\n");
+    Some((view_box, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribute_respects_name_boundaries() {
+        let attributes = r#" stroke-width="2" width="24" height="16px""#;
+        assert_eq!(attribute(attributes, "width"), Some("24"));
+        assert_eq!(attribute(attributes, "stroke-width"), Some("2"));
+        assert_eq!(attribute(r#" stroke-width="2""#, "width"), None);
+        assert_eq!(attribute(attributes, "fill"), None);
+    }
+
+    #[test]
+    fn recolor_replaces_solid_paints_only() {
+        assert_eq!(recolor(r##"<path fill="#000" stroke="rgb(0,0,0)"/>"##), r#"<path fill="currentColor" stroke="currentColor"/>"#);
+        assert_eq!(recolor(r#"<path fill="none" style="fill:none;stroke:black"/>"#), r#"<path fill="none" style="fill:none;stroke:currentColor"/>"#);
+        assert_eq!(recolor(r#"<path fill="currentColor"/>"#), r#"<path fill="currentColor"/>"#);
+    }
+
+    #[test]
+    fn inline_styles_moves_class_rules_onto_elements() {
+        let markup = "<defs><style>.cls-1{fill:none;}.cls-2{fill:#fff}</style></defs><rect class=\"cls-1\"/><path class=\"cls-2\"/>";
+        assert_eq!(inline_styles(markup), "<defs></defs><rect style=\"fill:none;\"/><path style=\"fill:#fff\"/>");
+    }
+
+    #[test]
+    fn normalize_wraps_presentation_attributes_and_strips_metadata() {
+        let source = r##"<?xml version="1.0"?><!-- generator --><svg width="32px" height="32px" fill="#000" stroke-width="2" xmlns="http://www.w3.org/2000/svg"><title>x</title><path id="a" d="M0 0"/></svg>"##;
+        let (view_box, body) = normalize(source).unwrap();
+        assert_eq!(view_box, "0 0 32 32");
+        assert_eq!(body, r#"<g fill="currentColor" stroke-width="2"><path d="M0 0"/></g>"#);
+    }
+
+    #[test]
+    fn normalize_keeps_gradient_artwork_untouched() {
+        let source = r##"<svg viewBox="0 0 8 8"><defs><linearGradient id="g"><stop stop-color="#f00"/></linearGradient></defs><path fill="url(#g)" stroke="#000"/></svg>"##;
+        let (view_box, body) = normalize(source).unwrap();
+        assert_eq!(view_box, "0 0 8 8");
+        assert!(body.contains(r#"id="g""#) && body.contains(r##"stroke="#000""##), "{body}");
+    }
+
+    #[test]
+    fn normalize_needs_an_svg_root_and_a_size() {
+        assert_eq!(normalize("<html></html>"), None);
+        assert_eq!(normalize("<svg><path/></svg>"), None);
+    }
+}
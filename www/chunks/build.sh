@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+# Builds every route chunk under chunks/ into $TRUNK_STAGING_DIR/chunks/<name>/.
+#
+# Each chunk is a cdylib compiled for wasm32 and post-processed with
+# `wasm-bindgen --target web`, so `www::chunks` can `import()` it at runtime.
+# The wasm-bindgen CLI must match the `wasm-bindgen` crate version in Cargo.lock.
+set -euo pipefail
+
+cd "$(dirname "$0")"
+out_root="${TRUNK_STAGING_DIR:-../dist}/chunks"
+export CARGO_TARGET_DIR="${PWD}/../target/chunks"
+profile="debug"
+cargo_flags=()
+if [[ "${TRUNK_PROFILE:-debug}" == "release" ]]; then
+  profile="release"
+  cargo_flags+=(--release)
+fi
+
+for manifest in */Cargo.toml; do
+  name="$(dirname "$manifest")"
+  crate="www-chunk-${name}"
+
+  cargo build "${cargo_flags[@]}" --manifest-path "$manifest" --target wasm32-unknown-unknown
+  wasm="${CARGO_TARGET_DIR}/wasm32-unknown-unknown/${profile}/${crate//-/_}.wasm"
+
+  mkdir -p "${out_root}/${name}"
+  wasm-bindgen --target web --no-typescript --out-dir "${out_root}/${name}" --out-name "${name}" "$wasm"
+
+  if [[ "$profile" == "release" ]] && command -v wasm-opt >/dev/null; then
+    wasm-opt -Oz -o "${out_root}/${name}/${name}_bg.wasm" "${out_root}/${name}/${name}_bg.wasm"
+  fi
+done
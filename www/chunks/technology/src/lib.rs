@@ -0,0 +1,176 @@
+//! Chunk for the /technology page: the technology graph, laid out with a
+//! force-directed simulation and drawn as SVG.
+//!
+//! The page around it lives in the main bundle. This chunk only uses
+//! web-sys, so loading it doesn't ship a second Leptos runtime.
+
+use std::cell::RefCell;
+use std::f64::consts::TAU;
+
+use wasm_bindgen::prelude::*;
+use web_sys::{Document, Element, HtmlElement};
+
+const SVG_NS: &str = "http://www.w3.org/2000/svg";
+const WIDTH: f64 = 720.0;
+const HEIGHT: f64 = 360.0;
+/// Keeps nodes and their labels inside the view box.
+const MARGIN: f64 = 48.0;
+const ITERATIONS: usize = 300;
+
+const NODES: &[&str] = &[
+    "Leptos",
+    "WebAssembly",
+    "Trunk",
+    "Nix",
+    "NATS",
+    "Memory Bank",
+    "Agents",
+    "Petgraph",
+    "Mermaid",
+    "Cypher",
+];
+
+/// Pairs of indices into `NODES`.
+const EDGES: &[(usize, usize)] = &[
+    (0, 1),
+    (2, 0),
+    (3, 2),
+    (0, 5),
+    (4, 5),
+    (6, 5),
+    (6, 4),
+    (5, 7),
+    (7, 8),
+    (7, 9),
+    (1, 7),
+];
+
+thread_local! {
+    static MOUNTED: RefCell<Option<Element>> = const { RefCell::new(None) };
+}
+
+/// Draw the graph into `host`, replacing any previous mount.
+#[wasm_bindgen]
+pub fn mount(host: HtmlElement) -> Result<(), JsValue> {
+    unmount();
+    let document = host.owner_document().ok_or_else(|| JsValue::from_str("host is not in a document"))?;
+    let svg = draw(&document, &layout())?;
+    host.append_child(&svg)?;
+    MOUNTED.with(|mounted| *mounted.borrow_mut() = Some(svg));
+    Ok(())
+}
+
+/// Remove the graph again.
+#[wasm_bindgen]
+pub fn unmount() {
+    if let Some(svg) = MOUNTED.with(|mounted| mounted.borrow_mut().take()) {
+        svg.remove();
+    }
+}
+
+/// Fruchterman–Reingold: nodes repel each other, edges pull their ends
+/// together, and the step size cools down until the layout settles. Nodes
+/// start on a circle, so the result is the same on every load.
+fn layout() -> Vec<(f64, f64)> {
+    let count = NODES.len();
+    let ideal = (WIDTH * HEIGHT / count as f64).sqrt() * 0.6;
+    let mut positions: Vec<(f64, f64)> = (0..count)
+        .map(|index| {
+            let angle = index as f64 / count as f64 * TAU;
+            (WIDTH / 2.0 + angle.cos() * WIDTH / 4.0, HEIGHT / 2.0 + angle.sin() * HEIGHT / 4.0)
+        })
+        .collect();
+
+    let mut temperature = WIDTH / 10.0;
+    for _ in 0..ITERATIONS {
+        let mut moves = vec![(0.0, 0.0); count];
+        for (i, a) in positions.iter().enumerate() {
+            for (j, b) in positions.iter().enumerate() {
+                if i != j {
+                    let (dx, dy) = (a.0 - b.0, a.1 - b.1);
+                    let distance = dx.hypot(dy).max(0.01);
+                    let force = ideal * ideal / distance;
+                    moves[i].0 += dx / distance * force;
+                    moves[i].1 += dy / distance * force;
+                }
+            }
+        }
+        for &(a, b) in EDGES {
+            let (dx, dy) = (positions[a].0 - positions[b].0, positions[a].1 - positions[b].1);
+            let distance = dx.hypot(dy).max(0.01);
+            let force = distance * distance / ideal;
+            moves[a].0 -= dx / distance * force;
+            moves[a].1 -= dy / distance * force;
+            moves[b].0 += dx / distance * force;
+            moves[b].1 += dy / distance * force;
+        }
+        for (position, (dx, dy)) in positions.iter_mut().zip(moves) {
+            let distance = dx.hypot(dy).max(0.01);
+            let step = distance.min(temperature);
+            position.0 = (position.0 + dx / distance * step).clamp(MARGIN, WIDTH - MARGIN);
+            position.1 = (position.1 + dy / distance * step).clamp(MARGIN, HEIGHT - MARGIN);
+        }
+        temperature *= 0.98;
+    }
+    positions
+}
+
+fn svg_element(document: &Document, tag: &str, attributes: &[(&str, String)]) -> Result<Element, JsValue> {
+    let element = document.create_element_ns(Some(SVG_NS), tag)?;
+    for (name, value) in attributes {
+        element.set_attribute(name, value)?;
+    }
+    Ok(element)
+}
+
+fn draw(document: &Document, positions: &[(f64, f64)]) -> Result<Element, JsValue> {
+    let svg = svg_element(
+        document,
+        "svg",
+        &[
+            ("viewBox", format!("0 0 {WIDTH} {HEIGHT}")),
+            ("role", "img".to_string()),
+            ("aria-label", format!("Graph of the technology stack: {}", NODES.join(", "))),
+            ("class", "w-full h-auto".to_string()),
+        ],
+    )?;
+
+    for &(a, b) in EDGES {
+        let ((x1, y1), (x2, y2)) = (positions[a], positions[b]);
+        let line = svg_element(
+            document,
+            "line",
+            &[
+                ("x1", x1.to_string()),
+                ("y1", y1.to_string()),
+                ("x2", x2.to_string()),
+                ("y2", y2.to_string()),
+                ("stroke-width", "1.5".to_string()),
+                ("class", "stroke-border".to_string()),
+            ],
+        )?;
+        svg.append_child(&line)?;
+    }
+
+    for (name, &(x, y)) in NODES.iter().zip(positions) {
+        let node = svg_element(
+            document,
+            "circle",
+            &[("cx", x.to_string()), ("cy", y.to_string()), ("r", "7".to_string()), ("class", "fill-accent".to_string())],
+        )?;
+        let label = svg_element(
+            document,
+            "text",
+            &[
+                ("x", x.to_string()),
+                ("y", (y + 24.0).to_string()),
+                ("text-anchor", "middle".to_string()),
+                ("class", "fill-text-secondary font-inter text-xs".to_string()),
+            ],
+        )?;
+        label.set_text_content(Some(name));
+        svg.append_child(&node)?;
+        svg.append_child(&label)?;
+    }
+    Ok(svg)
+}
@@ -0,0 +1,179 @@
+//! Generates responsive variants of the raster images in `assets/`.
+//!
+//! For every `assets/<name>.{jpg,jpeg,png}` this writes, into
+//! `assets/generated/`:
+//!
+//! - `<name>-<width>.avif`, `<name>-<width>.webp` and a fallback
+//!   `<name>-<width>.{jpg,png}` for each width in [`WIDTHS`] narrower than the
+//!   original (and the original width itself, up to [`MAX_WIDTH`]). The
+//!   fallback is a PNG only when the image has transparent pixels.
+//! - a line in `manifest.tsv` with the intrinsic size, dominant color, the
+//!   generated widths and a tiny blurred WebP as a data URI. `www/build.rs`
+//!   turns the manifest into the table the `Image` component reads.
+//!
+//! Images whose outputs are newer than the source are not re-encoded.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use base64::Engine;
+use image::codecs::avif::AvifEncoder;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, ImageFormat};
+
+/// Candidate widths, matching common layout breakpoints at 1x and 2x.
+const WIDTHS: &[u32] = &[320, 640, 960, 1280, 1920];
+const MAX_WIDTH: u32 = 1920;
+/// Width of the blurred placeholder.
+const PLACEHOLDER_WIDTH: u32 = 16;
+const MANIFEST_HEADER: &str = "# name\twidth\theight\tcolor\tfallback\twidths\tplaceholder";
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn main() {
+    let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets");
+    if let Err(err) = run(&assets) {
+        eprintln!("imagegen: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn run(assets: &Path) -> Result<()> {
+    let out = assets.join("generated");
+    fs::create_dir_all(&out)?;
+    let manifest_path = out.join("manifest.tsv");
+    let previous = read_manifest(&manifest_path);
+
+    let mut sources: Vec<PathBuf> = fs::read_dir(assets)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "jpg" | "jpeg" | "png"))
+        })
+        .collect();
+    sources.sort();
+
+    let mut manifest = vec![MANIFEST_HEADER.to_string()];
+    for source in sources {
+        let name = source.file_stem().and_then(|stem| stem.to_str()).ok_or("non utf-8 file name")?;
+        let fresh = previous.get(name).filter(|line| is_fresh(&source, &out, name, line));
+        let line = match fresh {
+            Some(line) => line.clone(),
+            None => {
+                println!("imagegen: {}", source.display());
+                process(&source, &out, name).map_err(|err| format!("{}: {err}", source.display()))?
+            }
+        };
+        manifest.push(line);
+    }
+    manifest.push(String::new());
+    fs::write(&manifest_path, manifest.join("\n"))?;
+    Ok(())
+}
+
+/// Manifest lines of the previous run, by image name.
+fn read_manifest(path: &Path) -> HashMap<String, String> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .filter_map(|line| Some((line.split('\t').next()?.to_string(), line.to_string())))
+        .collect()
+}
+
+/// Whether every output listed in a manifest line exists and is newer than
+/// the source.
+fn is_fresh(source: &Path, out: &Path, name: &str, line: &str) -> bool {
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [_, _, _, _, fallback, widths, _] = fields[..] else {
+        return false;
+    };
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    let Some(source_modified) = modified(source) else {
+        return false;
+    };
+    widths.split(',').all(|width| {
+        ["avif", "webp", fallback].iter().all(|ext| {
+            modified(&out.join(format!("{name}-{width}.{ext}")))
+                .is_some_and(|output: SystemTime| output >= source_modified)
+        })
+    })
+}
+
+/// Encode the variants of one image and return its manifest line.
+fn process(source: &Path, out: &Path, name: &str) -> Result<String> {
+    let image = image::open(source)?;
+    let (width, height) = image.dimensions();
+    let transparent = image.color().has_alpha() && image.to_rgba8().pixels().any(|pixel| pixel[3] < 255);
+    let image = if transparent {
+        DynamicImage::ImageRgba8(image.to_rgba8())
+    } else {
+        DynamicImage::ImageRgb8(image.to_rgb8())
+    };
+    let fallback = if transparent { "png" } else { "jpg" };
+
+    let mut widths: Vec<u32> = WIDTHS.iter().copied().filter(|&w| w < width).collect();
+    if width <= MAX_WIDTH {
+        widths.push(width);
+    }
+
+    for &w in &widths {
+        let h = ((height as u64 * w as u64 + width as u64 / 2) / width as u64).max(1) as u32;
+        let resized = if w == width { image.clone() } else { image.resize_exact(w, h, FilterType::Lanczos3) };
+        let path = |ext: &str| out.join(format!("{name}-{w}.{ext}"));
+
+        let webp = webp::Encoder::from_image(&resized).map_err(|err| err.to_string())?.encode(75.0);
+        fs::write(path("webp"), &*webp)?;
+
+        let avif = AvifEncoder::new_with_speed_quality(BufWriter::new(File::create(path("avif"))?), 6, 60);
+        resized.write_with_encoder(avif)?;
+
+        if transparent {
+            resized.save_with_format(path("png"), ImageFormat::Png)?;
+        } else {
+            let jpeg = JpegEncoder::new_with_quality(BufWriter::new(File::create(path("jpg"))?), 80);
+            resized.write_with_encoder(jpeg)?;
+        }
+    }
+
+    let widths = widths.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+    Ok(format!(
+        "{name}\t{width}\t{height}\t{}\t{fallback}\t{widths}\t{}",
+        dominant_color(&image),
+        placeholder(&image)?
+    ))
+}
+
+/// The most common color, as `#rrggbb`, ignoring transparent pixels.
+///
+/// Colors are bucketed at 4 bits per channel and the winning bucket's pixels
+/// are averaged, so noise and gradients don't split the vote.
+fn dominant_color(image: &DynamicImage) -> String {
+    let thumbnail = image.thumbnail(64, 64).to_rgba8();
+    let mut buckets: HashMap<u16, (u32, [u32; 3])> = HashMap::new();
+    for pixel in thumbnail.pixels().filter(|pixel| pixel[3] >= 128) {
+        let [r, g, b, _] = pixel.0;
+        let key = (r as u16 >> 4) << 8 | (g as u16 >> 4) << 4 | b as u16 >> 4;
+        let (count, sum) = buckets.entry(key).or_default();
+        *count += 1;
+        sum[0] += r as u32;
+        sum[1] += g as u32;
+        sum[2] += b as u32;
+    }
+    match buckets.into_values().max_by_key(|(count, _)| *count) {
+        Some((count, [r, g, b])) => format!("#{:02x}{:02x}{:02x}", r / count, g / count, b / count),
+        None => "transparent".to_string(),
+    }
+}
+
+/// A tiny WebP of the image as a data URI, shown blurred while it loads.
+fn placeholder(image: &DynamicImage) -> Result<String> {
+    let tiny = image.resize(PLACEHOLDER_WIDTH, PLACEHOLDER_WIDTH * 4, FilterType::Triangle);
+    let webp = webp::Encoder::from_image(&tiny).map_err(|err| err.to_string())?.encode(50.0);
+    Ok(format!("data:image/webp;base64,{}", base64::engine::general_purpose::STANDARD.encode(&*webp)))
+}
@@ -0,0 +1,93 @@
+//! Emits static redirect stub pages for the legacy URL table.
+//!
+//! GitHub Pages can't answer with real 301/302 responses, so every exact entry
+//! of [`www::redirects::REDIRECTS`] becomes a tiny HTML page that forwards the
+//! visitor with a meta refresh (plus `location.replace` to keep the query and
+//! hash). Pattern entries can't be enumerated ahead of time; for those we emit
+//! `404.html` as a copy of the app shell so the client-side router resolves them.
+//!
+//! Runs as a Trunk `post_build` hook and writes into `$TRUNK_STAGING_DIR`, or
+//! into the directory given as the first argument.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use www::redirects::{Redirect, REDIRECTS};
+
+fn main() -> io::Result<()> {
+    let out_dir = env::args()
+        .nth(1)
+        .or_else(|| env::var("TRUNK_STAGING_DIR").ok())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("dist"));
+
+    let mut written = 0;
+    for redirect in REDIRECTS.iter().filter(|redirect| redirect.is_exact()) {
+        let Some(file) = stub_path(&out_dir, redirect.from) else {
+            continue;
+        };
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&file, stub_html(redirect))?;
+        written += 1;
+    }
+
+    let shell = out_dir.join("index.html");
+    if shell.exists() {
+        fs::copy(&shell, out_dir.join("404.html"))?;
+    }
+
+    println!("redirect_stubs: wrote {} stub page(s) to {}", written, out_dir.display());
+    Ok(())
+}
+
+/// Where the stub for `from` lives inside `out_dir`. Paths that already name an
+/// `.html` file are written as-is; the app's own `index.html` is never replaced.
+fn stub_path(out_dir: &Path, from: &str) -> Option<PathBuf> {
+    let relative = from.trim_matches('/');
+    if relative.is_empty() || relative.eq_ignore_ascii_case("index.html") {
+        return None;
+    }
+
+    if relative.ends_with(".html") {
+        Some(out_dir.join(relative))
+    } else {
+        Some(out_dir.join(relative).join("index.html"))
+    }
+}
+
+fn stub_html(redirect: &Redirect) -> String {
+    let target = escape(redirect.to);
+    let (canonical, robots) = if redirect.kind.is_permanent() {
+        (format!(r#"<link rel="canonical" href="{target}">"#), "noindex, follow")
+    } else {
+        (String::new(), "noindex, nofollow")
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="UTF-8">
+  <meta name="robots" content="{robots}">
+  <meta http-equiv="refresh" content="0; url={target}">
+  {canonical}
+  <title>Redirecting…</title>
+  <script>location.replace("{target}" + location.search + location.hash);</script>
+</head>
+<body>
+  <p>This page has moved to <a href="{target}">{target}</a>.</p>
+</body>
+</html>
+"#
+    )
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,252 @@
+//! Route-level code splitting.
+//!
+//! The heavy parts of pages (graph visualisation, live demos) are compiled as
+//! separate wasm-bindgen modules under `chunks/<name>` and written to
+//! `chunks/<name>/` under Trunk's `public_url` by `chunks/build.sh` during
+//! `trunk build`. [`LazyChunk`] fetches and mounts a chunk on first render, and
+//! [`ChunkLink`] starts fetching it as soon as the pointer or focus reaches the
+//! link, so the module is usually ready by the time the click lands.
+//!
+//! The page around a chunk, with its design-system components and router
+//! links, stays in the main bundle. Chunks use wasm-bindgen and web-sys only:
+//! a chunk built with Leptos would carry a second copy of the runtime that
+//! can't see this app's contexts.
+//!
+//! Each chunk module exports `mount(host: HtmlElement)` and `unmount()`.
+
+use std::collections::HashMap;
+
+use js_sys::{Function, Promise, Reflect};
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::components::A;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::components::ErrorCard;
+
+/// Where a chunk is in its lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkState {
+    Idle,
+    Loading,
+    Ready,
+    Failed(String),
+}
+
+/// Tracks which chunks have been fetched and holds their initialised modules.
+#[derive(Clone, Copy)]
+pub struct ChunkRegistry {
+    states: RwSignal<HashMap<&'static str, ChunkState>>,
+    modules: StoredValue<HashMap<&'static str, JsValue>, LocalStorage>,
+}
+
+impl ChunkRegistry {
+    pub fn new() -> Self {
+        Self {
+            states: RwSignal::new(HashMap::new()),
+            modules: StoredValue::new_local(HashMap::new()),
+        }
+    }
+
+    /// Reactive state of `chunk`.
+    pub fn state(&self, chunk: &'static str) -> ChunkState {
+        self.states
+            .with(|states| states.get(chunk).cloned())
+            .unwrap_or(ChunkState::Idle)
+    }
+
+    /// Start fetching `chunk` unless it is already loading or loaded. Safe to
+    /// call repeatedly, e.g. on every hover.
+    pub fn preload(&self, chunk: &'static str) {
+        if matches!(self.states.with_untracked(|states| states.get(chunk).cloned()), Some(ChunkState::Loading | ChunkState::Ready)) {
+            return;
+        }
+        self.states.update(|states| {
+            states.insert(chunk, ChunkState::Loading);
+        });
+
+        let registry = *self;
+        spawn_local(async move {
+            let result = import_chunk(&chunk_url(chunk)).await;
+            let state = match result {
+                Ok(module) => {
+                    registry.modules.update_value(|modules| {
+                        modules.insert(chunk, module);
+                    });
+                    ChunkState::Ready
+                }
+                Err(err) => {
+                    log::error!("failed to load chunk {}: {:?}", chunk, err);
+                    ChunkState::Failed(describe(&err))
+                }
+            };
+            registry.states.update(|states| {
+                states.insert(chunk, state);
+            });
+        });
+    }
+
+    fn call(&self, chunk: &'static str, export: &str, arg: Option<&JsValue>) {
+        let result = self.modules.with_value(|modules| {
+            let module = modules.get(chunk).ok_or_else(|| JsValue::from_str("chunk not loaded"))?;
+            let function = Reflect::get(module, &JsValue::from_str(export))?.dyn_into::<Function>()?;
+            match arg {
+                Some(arg) => function.call1(&JsValue::NULL, arg),
+                None => function.call0(&JsValue::NULL),
+            }
+        });
+        if let Err(err) = result {
+            log::error!("chunk {}: {}() failed: {:?}", chunk, export, err);
+        }
+    }
+
+    fn mount(&self, chunk: &'static str, host: &web_sys::HtmlElement) {
+        self.call(chunk, "mount", Some(host.as_ref()));
+    }
+
+    fn unmount(&self, chunk: &'static str) {
+        self.call(chunk, "unmount", None);
+    }
+}
+
+impl Default for ChunkRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// wasm-bindgen emits this snippet as `snippets/<crate>-<hash>/inline0.js`
+// next to the app's JS, which Trunk serves from `public_url`; two levels up
+// from the snippet is therefore the public URL.
+#[wasm_bindgen(inline_js = "
+export function import_module(url) { return import(url); }
+export function public_root() { return new URL('../../', import.meta.url).href; }
+")]
+extern "C" {
+    /// `import(url)`, which Rust can't express. Unlike `new Function`, this
+    /// works under a CSP without `unsafe-eval`.
+    #[wasm_bindgen(js_name = import_module)]
+    fn import_js(url: &str) -> Promise;
+
+    fn public_root() -> String;
+}
+
+/// Dynamically import the ES module at `url`; the promise resolves to the
+/// module namespace.
+pub(crate) fn import_module(url: &str) -> Promise {
+    import_js(url)
+}
+
+/// `path` resolved against Trunk's `public_url`. Unlike a `<base>` tag,
+/// this leaves fragment-only links (`#id`) pointing at the current page.
+pub(crate) fn public_url(path: &str) -> String {
+    web_sys::Url::new_with_base(path, &public_root()).map(|url| url.href()).unwrap_or_else(|_| format!("/{path}"))
+}
+
+fn chunk_url(chunk: &str) -> String {
+    public_url(&format!("chunks/{chunk}/{chunk}.js"))
+}
+
+/// Dynamically import a wasm-bindgen `--target web` module and run its init.
+async fn import_chunk(url: &str) -> Result<JsValue, JsValue> {
+    let module = JsFuture::from(import_module(url)).await?;
+
+    let init = Reflect::get(&module, &JsValue::from_str("default"))?.dyn_into::<Function>()?;
+    JsFuture::from(Promise::from(init.call0(&JsValue::NULL)?)).await?;
+
+    Ok(module)
+}
+
+fn describe(err: &JsValue) -> String {
+    err.dyn_ref::<js_sys::Error>()
+        .map(|err| String::from(err.message()))
+        .or_else(|| err.as_string())
+        .unwrap_or_else(|| "unknown error".to_string())
+}
+
+/// Provides the [`ChunkRegistry`] to the app.
+#[component]
+pub fn ChunkProvider(children: Children) -> impl IntoView {
+    provide_context(ChunkRegistry::new());
+
+    view! {
+        {children()}
+    }
+}
+
+/// Loads `chunk` on first render and mounts it in place, showing a loading
+/// panel until the module is ready.
+#[component]
+pub fn LazyChunk(chunk: &'static str) -> impl IntoView {
+    let registry = expect_context::<ChunkRegistry>();
+    let host = NodeRef::<html::Div>::new();
+    let mounted = StoredValue::new(false);
+
+    registry.preload(chunk);
+
+    Effect::new(move |_| {
+        let ready = registry.state(chunk) == ChunkState::Ready;
+        if let (true, Some(el), false) = (ready, host.get(), mounted.get_value()) {
+            mounted.set_value(true);
+            registry.mount(chunk, &el);
+        }
+    });
+
+    on_cleanup(move || {
+        if mounted.try_get_value().unwrap_or(false) {
+            registry.unmount(chunk);
+        }
+    });
+
+    view! {
+        <div node_ref=host data-chunk=chunk></div>
+        {move || match registry.state(chunk) {
+            ChunkState::Idle | ChunkState::Loading => Some(view! { <ChunkLoading /> }.into_any()),
+            ChunkState::Failed(message) => Some(view! {
+                <ErrorCard
+                    title="This section failed to load."
+                    messages=vec![message]
+                    on_retry=move || registry.preload(chunk)
+                />
+            }.into_any()),
+            ChunkState::Ready => None,
+        }}
+    }
+}
+
+/// Router link that preloads `chunk` on hover or focus.
+#[component]
+pub fn ChunkLink(
+    href: &'static str,
+    chunk: &'static str,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let registry = expect_context::<ChunkRegistry>();
+
+    view! {
+        <span
+            class="contents"
+            on:pointerenter=move |_| registry.preload(chunk)
+            on:focusin=move |_| registry.preload(chunk)
+        >
+            <A href=href attr:class=class.unwrap_or_default()>
+                {children()}
+            </A>
+        </span>
+    }
+}
+
+/// Loading panel shown while a chunk is fetched.
+#[component]
+fn ChunkLoading() -> impl IntoView {
+    view! {
+        <div class="glass-card flex flex-col items-center justify-center gap-4 py-16" role="status" aria-live="polite">
+            <div class="w-10 h-10 rounded-full border-4 border-base02 border-t-accent animate-spin motion-reduce:animate-none"></div>
+            <p class="text-sm font-inter text-text-secondary">"Loading…"</p>
+        </div>
+    }
+}
@@ -1,80 +1,216 @@
 use leptos::*;
+use leptos::callback::UnsyncCallback;
 use leptos::prelude::*;
 use leptos::html::ElementChild;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum ButtonVariant {
+    #[default]
     Primary,
     Secondary,
     Text,
 }
 
+impl ButtonVariant {
+    pub fn class(&self) -> &'static str {
+        match self {
+            ButtonVariant::Primary => "btn-primary",
+            ButtonVariant::Secondary => "btn-secondary",
+            ButtonVariant::Text => "btn-text",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ButtonSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl ButtonSize {
+    pub fn class(&self) -> &'static str {
+        match self {
+            ButtonSize::Small => "btn-sm",
+            ButtonSize::Medium => "",
+            ButtonSize::Large => "btn-lg",
+        }
+    }
+}
+
+/// The `type` attribute of a rendered `<button>`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ButtonType {
+    #[default]
+    Button,
+    Submit,
+    Reset,
+}
+
+impl ButtonType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ButtonType::Button => "button",
+            ButtonType::Submit => "submit",
+            ButtonType::Reset => "reset",
+        }
+    }
+}
+
+/// Button with variants, sizes, optional icons and a loading state.
+///
+/// Styling props and `disabled`/`loading` are signals, so a plain value, a
+/// signal or `Signal::derive(..)` all work. `on_click` is an `UnsyncCallback`
+/// (mouse events aren't `Send`), which is `Copy` and can be shared between
+/// buttons. Passing `href` renders an `<a>` with identical styling instead of
+/// a `<button>`. While `loading` is true the button is disabled, shows a
+/// spinner in place of the leading icon and sets `aria-busy`.
 #[component]
 pub fn Button(
-    #[prop(into, optional)] label: MaybeSignal<String>,
-    #[prop(optional)] variant: Option<ButtonVariant>,
-    #[prop(optional)] class: Option<String>,
-    #[prop(optional)] on_click: Option<Box<dyn Fn(ev::MouseEvent) + 'static>>,
-    #[prop(optional)] disabled: Option<bool>,
+    #[prop(into, optional)] label: Signal<String>,
+    #[prop(into, optional)] variant: Signal<ButtonVariant>,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(optional)] button_type: ButtonType,
+    #[prop(optional, into)] href: Option<String>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] on_click: Option<UnsyncCallback<(ev::MouseEvent,)>>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] loading: Signal<bool>,
+    #[prop(optional, into)] leading_icon: Option<ViewFn>,
+    #[prop(optional, into)] trailing_icon: Option<ViewFn>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
-    let variant = variant.unwrap_or(ButtonVariant::Primary);
-    let disabled = disabled.unwrap_or(false);
+    let inactive = move || disabled.get() || loading.get();
 
     let base_class = move || {
-        let variant_class = match variant {
-            ButtonVariant::Primary => "btn-primary",
-            ButtonVariant::Secondary => "btn-secondary",
-            ButtonVariant::Text => "btn-text",
-        };
-        
-        let disabled_class = if disabled { " opacity-50 cursor-not-allowed" } else { "" };
-        
-        format!("{}{} {}", variant_class, disabled_class, class.clone().unwrap_or_default())
+        let disabled_class = if inactive() { " opacity-50 cursor-not-allowed" } else { "" };
+
+        format!(
+            "{} {} inline-flex items-center justify-center gap-2{} {}",
+            variant.get().class(),
+            size.get().class(),
+            disabled_class,
+            class.get()
+        )
     };
 
-    view! {
-        <button 
-            class=base_class
-            on:click=move |evt| {
-                if !disabled {
-                    if let Some(on_click) = on_click.as_ref() {
-                        on_click(evt)
-                    }
-                }
+    let handle_click = move |evt: ev::MouseEvent| {
+        if inactive() {
+            evt.prevent_default();
+            return;
+        }
+        if let Some(on_click) = on_click {
+            on_click.run((evt,))
+        }
+    };
+
+    let content = view! {
+        {move || {
+            if loading.get() {
+                Some(view! { <ButtonSpinner /> }.into_any())
+            } else {
+                leading_icon.as_ref().map(|icon| view! { <span class="shrink-0" aria-hidden="true">{icon.run()}</span> }.into_any())
             }
-            disabled=disabled
-        >
-            {move || label.get()}
-            {children.map(|children| children())}
-        </button>
+        }}
+        {move || label.get()}
+        {children.map(|children| children())}
+        {trailing_icon.map(|icon| view! { <span class="shrink-0" aria-hidden="true">{icon.run()}</span> })}
+    };
+
+    match href {
+        Some(href) => view! {
+            <a
+                class=base_class
+                href=move || (!inactive()).then(|| href.clone())
+                role=move || inactive().then_some("link")
+                aria-disabled=move || inactive().then_some("true")
+                aria-busy=move || loading.get().then_some("true")
+                tabindex=move || inactive().then_some("-1")
+                on:click=handle_click
+            >
+                {content}
+            </a>
+        }
+        .into_any(),
+        None => view! {
+            <button
+                class=base_class
+                type=button_type.as_str()
+                on:click=handle_click
+                disabled=inactive
+                aria-busy=move || loading.get().then_some("true")
+            >
+                {content}
+            </button>
+        }
+        .into_any(),
+    }
+}
+
+/// Inline spinner sized to the surrounding text.
+#[component]
+fn ButtonSpinner() -> impl IntoView {
+    view! {
+        <span
+            class="w-[1em] h-[1em] shrink-0 rounded-full border-2 border-current border-t-transparent animate-spin motion-reduce:animate-none"
+            aria-hidden="true"
+        ></span>
     }
 }
 
 // Usage example component that shows both button variants
 #[component]
 pub fn ButtonShowcase() -> impl IntoView {
-    let (count, set_count) = create_signal(0);
-    let increment = move |evt: ev::MouseEvent| set_count.update(|n| *n += 1);
+    let (count, set_count) = signal(0);
+    let increment = UnsyncCallback::new(move |_: (ev::MouseEvent,)| set_count.update(|n| *n += 1));
+    let saving = RwSignal::new(false);
+    let email = RwSignal::new(String::new());
+    let email_invalid = Signal::derive(move || !email.with(|email| email.contains('@')));
 
     view! {
         <div>
             <h2>"Button Showcase"</h2>
             <div class="flex gap-2 items-center">
-                <Button on_click=Box::new(increment) variant=ButtonVariant::Primary>
+                <Button on_click=increment variant=ButtonVariant::Primary>
                     "Primary Button"
                 </Button>
-                <Button on_click=Box::new(increment) variant=ButtonVariant::Secondary>
+                <Button on_click=increment variant=ButtonVariant::Secondary>
                     "Secondary Button"
                 </Button>
-                <Button on_click=Box::new(increment) variant=ButtonVariant::Text>
+                <Button on_click=increment variant=ButtonVariant::Text>
                     "Text Button"
                 </Button>
-                <Button on_click=Box::new(increment) disabled=true>
+                <Button on_click=increment disabled=true>
                     "Disabled Button"
                 </Button>
                 <div>"Count: " {count}</div>
             </div>
+            <div class="flex gap-2 items-center mt-4">
+                <Button size=ButtonSize::Small>"Small"</Button>
+                <Button size=ButtonSize::Medium>"Medium"</Button>
+                <Button size=ButtonSize::Large>"Large"</Button>
+                <Button
+                    loading=saving
+                    on_click=move |_| saving.set(true)
+                    trailing_icon=|| "→"
+                >
+                    "Save"
+                </Button>
+                <Button href="/showcase" variant=ButtonVariant::Secondary>"Link Button"</Button>
+            </div>
+            <form class="flex gap-2 items-center mt-4" on:submit=move |ev| ev.prevent_default()>
+                <input
+                    class="input"
+                    type="email"
+                    placeholder="you@example.com"
+                    prop:value=move || email.get()
+                    on:input=move |ev| email.set(event_target_value(&ev))
+                />
+                <Button button_type=ButtonType::Submit disabled=email_invalid>
+                    "Submit"
+                </Button>
+            </form>
         </div>
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,301 @@
+use leptos::callback::UnsyncCallback;
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+
+use crate::components::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::roving::{self, Orientation};
+
+/// One segment of a [`ButtonGroup`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToggleOption {
+    pub value: String,
+    pub label: String,
+}
+
+impl ToggleOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self { value: value.into(), label: label.into() }
+    }
+}
+
+/// Whether a [`ButtonGroup`] behaves like radio buttons or a set of toggles.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    Multiple,
+}
+
+/// Classes for a toggle-style button: pressed segments take the primary look.
+fn toggle_class(pressed: bool, size: ButtonSize) -> String {
+    let variant = if pressed { ButtonVariant::Primary } else { ButtonVariant::Secondary };
+    format!("{} {} inline-flex items-center justify-center gap-2", variant.class(), size.class())
+}
+
+/// Segmented control, e.g. a graph layout picker or a time range selector.
+///
+/// In `Single` mode the group is a `radiogroup`: arrow keys move and select.
+/// In `Multiple` mode each segment is an `aria-pressed` toggle: arrow keys
+/// move focus and Space/Enter toggle. Either way only one segment is in the
+/// tab order (roving tabindex).
+#[component]
+pub fn ButtonGroup(
+    options: Vec<ToggleOption>,
+    selected: RwSignal<Vec<String>>,
+    #[prop(into)] label: String,
+    #[prop(optional)] mode: SelectionMode,
+    #[prop(optional)] orientation: Orientation,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(optional, into)] class: Signal<String>,
+) -> impl IntoView {
+    let count = options.len();
+    let initial = selected.with_untracked(|selected| {
+        options.iter().position(|option| selected.contains(&option.value)).unwrap_or(0)
+    });
+    let active = RwSignal::new(initial);
+
+    let select = move |value: String| {
+        selected.update(|selected| match mode {
+            SelectionMode::Single => *selected = vec![value],
+            SelectionMode::Multiple => {
+                if let Some(pos) = selected.iter().position(|v| *v == value) {
+                    selected.remove(pos);
+                } else {
+                    selected.push(value);
+                }
+            }
+        });
+    };
+
+    let values: Vec<String> = options.iter().map(|option| option.value.clone()).collect();
+    let direction = match orientation {
+        Orientation::Horizontal => "flex-row",
+        Orientation::Vertical => "flex-col",
+    };
+
+    view! {
+        <div
+            role=match mode {
+                SelectionMode::Single => "radiogroup",
+                SelectionMode::Multiple => "group",
+            }
+            aria-label=label
+            aria-orientation=match orientation {
+                Orientation::Horizontal => "horizontal",
+                Orientation::Vertical => "vertical",
+            }
+            class=move || format!("inline-flex {} gap-px {}", direction, class.get())
+        >
+            {options
+                .into_iter()
+                .enumerate()
+                .map(|(index, option)| {
+                    let value = option.value.clone();
+                    let is_selected = {
+                        let value = value.clone();
+                        move || selected.with(|selected| selected.contains(&value))
+                    };
+                    let is_selected_class = is_selected.clone();
+                    let is_selected_checked = is_selected.clone();
+                    let click_value = value.clone();
+                    let values = values.clone();
+
+                    view! {
+                        <button
+                            type="button"
+                            role=(mode == SelectionMode::Single).then_some("radio")
+                            aria-checked=move || (mode == SelectionMode::Single).then(|| is_selected_checked().to_string())
+                            aria-pressed=move || (mode == SelectionMode::Multiple).then(|| is_selected().to_string())
+                            tabindex=move || roving::tab_index(index, active.get())
+                            disabled=move || disabled.get()
+                            class=move || format!("{} rounded-none first:rounded-l last:rounded-r", toggle_class(is_selected_class(), size.get()))
+                            on:click=move |_| {
+                                active.set(index);
+                                select(click_value.clone());
+                            }
+                            on:keydown=move |ev: ev::KeyboardEvent| {
+                                if let Some(next) = roving::handle_keydown(&ev, index, count, orientation) {
+                                    active.set(next);
+                                    if mode == SelectionMode::Single {
+                                        select(values[next].clone());
+                                    }
+                                }
+                            }
+                        >
+                            {option.label}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+/// Standalone on/off button exposing its state through `aria-pressed`.
+#[component]
+pub fn ToggleButton(
+    pressed: RwSignal<bool>,
+    #[prop(optional, into)] on_toggle: Option<Callback<(bool,)>>,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(optional, into)] class: Signal<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <button
+            type="button"
+            aria-pressed=move || pressed.get().to_string()
+            disabled=move || disabled.get()
+            class=move || format!("{} {}", toggle_class(pressed.get(), size.get()), class.get())
+            on:click=move |_| {
+                pressed.update(|pressed| *pressed = !*pressed);
+                if let Some(on_toggle) = on_toggle {
+                    on_toggle.run((pressed.get_untracked(),));
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// An alternative action offered in a [`SplitButton`] dropdown.
+#[derive(Clone)]
+pub struct SplitAction {
+    pub label: String,
+    pub on_select: Callback<()>,
+}
+
+impl SplitAction {
+    pub fn new(label: impl Into<String>, on_select: impl Fn() + Send + Sync + 'static) -> Self {
+        Self { label: label.into(), on_select: Callback::new(move |_| on_select()) }
+    }
+}
+
+/// A primary action plus a dropdown of alternatives.
+///
+/// The caret opens a `menu`; arrow keys move between items, Escape or
+/// focus leaving the widget closes it and returns focus to the caret.
+#[component]
+pub fn SplitButton(
+    #[prop(into)] label: Signal<String>,
+    actions: Vec<SplitAction>,
+    #[prop(optional, into)] on_click: Option<UnsyncCallback<(ev::MouseEvent,)>>,
+    #[prop(into, optional)] variant: Signal<ButtonVariant>,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] loading: Signal<bool>,
+    #[prop(optional, into)] menu_label: Option<String>,
+) -> impl IntoView {
+    let open = RwSignal::new(false);
+    let toggle_ref = NodeRef::<html::Button>::new();
+    let menu_ref = NodeRef::<html::Div>::new();
+    let count = actions.len();
+
+    let focus_item = move |index: usize| {
+        request_animation_frame(move || {
+            let item = menu_ref
+                .get_untracked()
+                .and_then(|menu| menu.children().item(index as u32))
+                .and_then(|item| wasm_bindgen::JsCast::dyn_into::<web_sys::HtmlElement>(item).ok());
+            if let Some(item) = item {
+                let _ = item.focus();
+            }
+        });
+    };
+    let close = move |refocus: bool| {
+        open.set(false);
+        if refocus {
+            if let Some(toggle) = toggle_ref.get_untracked() {
+                let _ = toggle.focus();
+            }
+        }
+    };
+
+    let on_focusout = move |ev: ev::FocusEvent| {
+        let container = ev
+            .current_target()
+            .and_then(|target| wasm_bindgen::JsCast::dyn_into::<web_sys::Node>(target).ok());
+        let next = ev
+            .related_target()
+            .and_then(|target| wasm_bindgen::JsCast::dyn_into::<web_sys::Node>(target).ok());
+        let inside = matches!((container, next), (Some(container), Some(next)) if container.contains(Some(&next)));
+        if !inside {
+            open.set(false);
+        }
+    };
+
+    view! {
+        <div class="relative inline-flex" on:focusout=on_focusout>
+            <Button
+                label=label
+                variant=variant
+                size=size
+                disabled=disabled
+                loading=loading
+                on_click=on_click.unwrap_or_else(|| UnsyncCallback::new(|_| {}))
+                class="rounded-r-none"
+            />
+            <button
+                node_ref=toggle_ref
+                type="button"
+                aria-haspopup="menu"
+                aria-expanded=move || open.get().to_string()
+                aria-label=menu_label.unwrap_or_else(|| "More options".to_string())
+                disabled=move || disabled.get() || loading.get()
+                class=move || format!(
+                    "{} {} rounded-l-none border-l border-base00/30 px-2",
+                    variant.get().class(),
+                    size.get().class(),
+                )
+                on:click=move |_| open.update(|open| *open = !*open)
+                on:keydown=move |ev: ev::KeyboardEvent| {
+                    if matches!(ev.key().as_str(), "ArrowDown" | "ArrowUp") {
+                        ev.prevent_default();
+                        open.set(true);
+                        focus_item(if ev.key() == "ArrowUp" { count.saturating_sub(1) } else { 0 });
+                    }
+                }
+            >
+                <span aria-hidden="true">"▾"</span>
+            </button>
+            <div
+                node_ref=menu_ref
+                role="menu"
+                class="absolute right-0 top-full mt-1 z-20 min-w-full glass-card p-1 flex flex-col"
+                class:hidden=move || !open.get()
+            >
+                {actions
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, SplitAction { label, on_select })| view! {
+                        <button
+                            type="button"
+                            role="menuitem"
+                            tabindex="-1"
+                            class="text-left whitespace-nowrap px-3 py-2 rounded text-text-primary hover:bg-base02 focus:bg-base02"
+                            on:click=move |_| {
+                                close(true);
+                                on_select.run(());
+                            }
+                            on:keydown=move |ev: ev::KeyboardEvent| {
+                                if ev.key() == "Escape" || ev.key() == "Tab" {
+                                    if ev.key() == "Escape" {
+                                        ev.prevent_default();
+                                    }
+                                    close(ev.key() == "Escape");
+                                } else {
+                                    roving::handle_keydown(&ev, index, count, Orientation::Vertical);
+                                }
+                            }
+                        >
+                            {label}
+                        </button>
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+}
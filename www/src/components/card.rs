@@ -1,38 +1,196 @@
 use leptos::*;
 use leptos::prelude::*;
+use leptos::context::Provider;
 use leptos::html::ElementChild;
+use leptos_router::components::A;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum CardVariant {
+    #[default]
     Basic,
     Elevated,
     Glass,
+    /// GlassCard with silver edges, a glowing connection and a metallic sheen on hover.
+    GlowEdge,
 }
 
+impl CardVariant {
+    pub fn class(&self) -> &'static str {
+        match self {
+            CardVariant::Basic => "bg-surface rounded-lg p-6 ",
+            CardVariant::Elevated => "bg-surface-elevated rounded-lg shadow-md p-6 ",
+            CardVariant::Glass => "backdrop-blur-sm bg-surface/70 rounded-lg shadow-md p-6 ",
+            CardVariant::GlowEdge => "glass-card-glow ",
+        }
+    }
+}
+
+/// Link target of the enclosing card, read by `CardHeader` to render its title
+/// as the card's single accessible link. Every card provides one, so a card
+/// nested in a linked card isn't linked too.
+#[derive(Clone, Debug)]
+struct CardLink(Option<String>);
+
+/// Content container. Styling props are signals so a card can change
+/// variant or highlight reactively.
+///
+/// With `href` the whole card becomes clickable through its `CardHeader`
+/// title link, so screen readers announce one link instead of the entire
+/// card's content; put other interactive children in `relative z-10`.
+/// The link is the header's title, so `href` has no effect on a card
+/// without a `CardHeader`.
+/// With `on_select` the card is a checkbox (or a radio with `radio`) for
+/// use in pickers, toggled by click, Space or Enter.
 #[component]
 pub fn Card(
-    #[prop(default = false)] hoverable: bool,
-    #[prop(default = CardVariant::Basic)] variant: CardVariant,
-    #[prop(default = "")] class: &'static str,
-    #[prop(into)] children: Children,
+    #[prop(into, optional)] hoverable: Signal<bool>,
+    #[prop(into, optional)] variant: Signal<CardVariant>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] href: Option<String>,
+    #[prop(into, optional)] selected: Signal<bool>,
+    #[prop(optional, into)] on_select: Option<Callback<(bool,)>>,
+    #[prop(optional)] radio: bool,
+    children: Children,
 ) -> impl IntoView {
-    let mut class_list = match variant {
-        CardVariant::Basic => "bg-surface rounded-lg p-6 ".to_string(),
-        CardVariant::Elevated => "bg-surface-elevated rounded-lg shadow-md p-6 ".to_string(),
-        CardVariant::Glass => "backdrop-blur-sm bg-surface/70 rounded-lg shadow-md p-6 ".to_string(),
+    let selectable = on_select.is_some();
+    let link = CardLink(href.filter(|_| !selectable));
+    let linked = link.0.is_some();
+
+    let class_list = move || {
+        let mut class_list = variant.get().class().to_string();
+
+        // Add hoverable effect if requested
+        if hoverable.get() || linked || selectable {
+            class_list.push_str("transition-transform duration-200 hover:-translate-y-1 hover:shadow-lg ");
+        }
+        if linked {
+            class_list.push_str("relative focus-within:ring-2 focus-within:ring-accent ");
+        }
+        if selectable {
+            class_list.push_str("relative cursor-pointer select-none ");
+            if selected.get() {
+                class_list.push_str("ring-2 ring-accent shadow-[0_0_12px_2px_theme(colors.glow)] ");
+            }
+        }
+
+        // Add any custom classes
+        class_list.push_str(&class.get());
+        class_list
+    };
+
+    let toggle = move || {
+        if let Some(on_select) = on_select {
+            // Radios only ever select; deselection happens by choosing another.
+            let next = if radio { true } else { !selected.get_untracked() };
+            on_select.run((next,));
+        }
     };
-    
-    // Add hoverable effect if requested
-    if hoverable {
-        class_list.push_str("transition-transform duration-200 hover:-translate-y-1 hover:shadow-lg ");
+
+    view! {
+        <div
+            class=class_list
+            role=selectable.then_some(if radio { "radio" } else { "checkbox" })
+            aria-checked=move || selectable.then(|| selected.get().to_string())
+            tabindex=selectable.then_some("0")
+            on:click=move |_| toggle()
+            on:keydown=move |ev: ev::KeyboardEvent| {
+                if selectable && matches!(ev.key().as_str(), " " | "Enter") {
+                    ev.prevent_default();
+                    toggle();
+                }
+            }
+        >
+            {selectable.then(|| view! {
+                <span
+                    class="absolute top-3 right-3 w-5 h-5 rounded-full border-2 border-accent flex items-center justify-center text-xs transition-colors"
+                    class:bg-accent=move || selected.get()
+                    aria-hidden="true"
+                >
+                    {move || selected.get().then_some("✓")}
+                </span>
+            })}
+            <Provider value=link>{children()}</Provider>
+        </div>
+    }
+}
+
+/// Card title row with optional subtitle and trailing actions.
+#[component]
+pub fn CardHeader(
+    #[prop(into)] title: String,
+    #[prop(optional, into)] subtitle: Option<String>,
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let class_value = format!("flex items-start justify-between gap-4 mb-4 {}", class.unwrap_or_default());
+    let title = match use_context::<CardLink>() {
+        Some(CardLink(Some(href))) => view! {
+            <A
+                href=href
+                attr:class="text-text-primary hover:text-accent focus:outline-none after:absolute after:inset-0 after:content-['']"
+            >
+                {title}
+            </A>
+        }
+        .into_any(),
+        _ => title.into_any(),
+    };
+
+    view! {
+        <div class=class_value>
+            <div>
+                <h3 class="text-xl font-outfit font-semibold">{title}</h3>
+                {subtitle.map(|subtitle| view! {
+                    <p class="text-sm font-inter text-text-secondary mt-1">{subtitle}</p>
+                })}
+            </div>
+            {children.map(|children| view! {
+                <div class="relative z-10 flex items-center gap-2 shrink-0">{children()}</div>
+            })}
+        </div>
     }
-    
-    // Add any custom classes
-    class_list.push_str(class);
-    
+}
+
+/// Full-bleed image at the top of a card.
+#[component]
+pub fn CardMedia(
+    #[prop(into)] src: String,
+    #[prop(into)] alt: String,
+    #[prop(default = "aspect-video")] aspect: &'static str,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let class_value = format!("-mx-6 -mt-6 mb-4 overflow-hidden rounded-t-lg {} {}", aspect, class.unwrap_or_default());
+
     view! {
-        <div class={class_list}>
+        <div class=class_value>
+            <img src=src alt=alt class="w-full h-full object-cover" loading="lazy" />
+        </div>
+    }
+}
+
+/// Main content of a card.
+#[component]
+pub fn CardBody(#[prop(optional, into)] class: Option<String>, children: Children) -> impl IntoView {
+    let class_value = format!("font-inter text-text-primary space-y-2 {}", class.unwrap_or_default());
+
+    view! {
+        <div class=class_value>
+            {children()}
+        </div>
+    }
+}
+
+/// Bottom row of a card, typically actions or metadata.
+#[component]
+pub fn CardFooter(#[prop(optional, into)] class: Option<String>, children: Children) -> impl IntoView {
+    let class_value = format!(
+        "relative z-10 mt-4 pt-4 border-t border-border/50 flex items-center gap-2 {}",
+        class.unwrap_or_default()
+    );
+
+    view! {
+        <div class=class_value>
             {children()}
         </div>
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::components::highlight::{self, DiffMarker, Language, LineRanges, TokenKind};
+
+/// One rendered line: its number, diff marker and highlighted tokens.
+struct Line {
+    number: usize,
+    marker: DiffMarker,
+    tokens: Vec<(TokenKind, String)>,
+}
+
+/// Highlighted, copyable code listing.
+///
+/// `language` takes a fence name (`rust`, `toml`, `nix`, `json`, `sh`,
+/// `cypher`, `mermaid`); a `diff-` prefix (or `diff`) turns on diff markers,
+/// where each line starts with `+`, `-` or a space. `highlight` marks lines
+/// like `"1,3-5"`. The copy button copies the code without diff markers or
+/// removed lines.
+#[component]
+pub fn CodeBlock(
+    #[prop(into)] code: String,
+    #[prop(optional, into)] language: Option<String>,
+    #[prop(optional)] line_numbers: bool,
+    #[prop(optional, into)] highlight: LineRanges,
+    #[prop(optional)] diff: bool,
+    #[prop(default = true)] copyable: bool,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let language = language.unwrap_or_default();
+    let (diff, language) = match language.strip_prefix("diff") {
+        Some(rest) => (true, Language::from_name(rest.trim_start_matches('-'))),
+        None => (diff, Language::from_name(&language)),
+    };
+
+    let (markers, source): (Vec<DiffMarker>, Vec<&str>) = if diff {
+        code.lines().map(DiffMarker::split).unzip()
+    } else {
+        code.lines().map(|line| (DiffMarker::Unchanged, line)).unzip()
+    };
+    let source = source.join("\n");
+
+    let copy_text = if diff {
+        markers
+            .iter()
+            .zip(source.lines())
+            .filter(|(marker, _)| **marker != DiffMarker::Removed)
+            .map(|(_, line)| line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        code.clone()
+    };
+
+    let lines: Vec<Line> = highlight::highlight_lines(language, &source)
+        .into_iter()
+        .enumerate()
+        .map(|(index, tokens)| Line {
+            number: index + 1,
+            marker: markers.get(index).copied().unwrap_or(DiffMarker::Unchanged),
+            tokens: tokens.into_iter().map(|token| (token.kind, token.text.to_string())).collect(),
+        })
+        .collect();
+    let gutter_width = format!("min-width: {}ch", lines.len().to_string().len() + 1);
+
+    view! {
+        <div class=format!("relative group {}", class.unwrap_or_default())>
+            <pre class="p-4 rounded-md bg-surface-elevated overflow-x-auto" data-language=language.label()>
+                <code class="font-mono text-sm block w-max min-w-full">
+                    {lines
+                        .into_iter()
+                        .map(|Line { number, marker, tokens }| {
+                            let highlighted = highlight.contains(number);
+                            let line_class = format!(
+                                "block -mx-4 px-4 {} {}",
+                                marker.line_class(),
+                                if highlighted { "bg-base02 shadow-[inset_2px_0_0_theme(colors.accent)]" } else { "" },
+                            );
+                            let empty = tokens.is_empty();
+
+                            view! {
+                                <span class=line_class>
+                                    {line_numbers.then(|| view! {
+                                        <span
+                                            class="inline-block pr-4 text-right text-base03 select-none"
+                                            style=gutter_width.clone()
+                                            aria-hidden="true"
+                                        >
+                                            {number}
+                                        </span>
+                                    })}
+                                    {diff.then(|| view! {
+                                        <span class=format!("inline-block w-4 select-none {}", marker.marker_class())>
+                                            {marker.symbol()}
+                                        </span>
+                                    })}
+                                    {tokens
+                                        .into_iter()
+                                        .map(|(kind, text)| match kind {
+                                            TokenKind::Plain => text.into_any(),
+                                            kind => view! { <span class=kind.class()>{text}</span> }.into_any(),
+                                        })
+                                        .collect_view()}
+                                    // Keep blank lines one line tall.
+                                    {empty.then_some(" ")}
+                                </span>
+                            }
+                        })
+                        .collect_view()}
+                </code>
+            </pre>
+            {copyable.then(|| view! { <CopyButton text=copy_text /> })}
+        </div>
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CopyState {
+    Idle,
+    Copied,
+    Failed,
+}
+
+/// Copies `text` to the clipboard and briefly reports the outcome, both
+/// visibly and through a polite live region.
+#[component]
+fn CopyButton(text: String) -> impl IntoView {
+    let state = RwSignal::new(CopyState::Idle);
+    let text = StoredValue::new(text);
+
+    let copy = move |_| {
+        let clipboard = window().navigator().clipboard();
+        // `navigator.clipboard` is missing outside secure contexts.
+        if clipboard.is_undefined() {
+            state.set(CopyState::Failed);
+            return;
+        }
+        let promise = clipboard.write_text(&text.get_value());
+        spawn_local(async move {
+            let copied = JsFuture::from(promise).await.is_ok();
+            state.try_set(if copied { CopyState::Copied } else { CopyState::Failed });
+            set_timeout(move || { state.try_set(CopyState::Idle); }, Duration::from_secs(2));
+        });
+    };
+
+    view! {
+        <button
+            type="button"
+            class="absolute top-2 right-2 px-2 py-1 rounded text-xs font-inter bg-surface/80 text-text-secondary border border-border/50 hover:text-accent transition-opacity motion-reduce:transition-none sm:opacity-0 sm:group-hover:opacity-100 sm:focus:opacity-100"
+            class:text-success=move || state.get() == CopyState::Copied
+            class:text-error=move || state.get() == CopyState::Failed
+            on:click=copy
+        >
+            {move || match state.get() {
+                CopyState::Idle => "Copy",
+                CopyState::Copied => "Copied!",
+                CopyState::Failed => "Copy failed",
+            }}
+        </button>
+        <span class="sr-only" role="status" aria-live="polite">
+            {move || match state.get() {
+                CopyState::Idle => "",
+                CopyState::Copied => "Code copied to clipboard",
+                CopyState::Failed => "Could not copy code",
+            }}
+        </span>
+    }
+}
@@ -0,0 +1,625 @@
+//! Site-wide command palette, opened with Ctrl+K (⌘K on macOS).
+//!
+//! [`CommandProvider`] owns a [`CommandRegistry`] that any component below
+//! it can add commands to through [`use_commands`]:
+//!
+//! ```ignore
+//! let commands = use_commands();
+//! commands.register_scoped(
+//!     Command::action("chat.open", "Open chat", Callback::new(move |_| chat_open.set(true)))
+//!         .keywords(["assistant", "ask"])
+//!         .shortcut("Mod+/"),
+//! );
+//! ```
+//!
+//! Besides registered commands, the palette lists the headings (with ids)
+//! of the page that is open. Results are fuzzy-matched, grouped and the last
+//! few commands run are remembered across visits.
+
+use leptos::ev;
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use leptos_router::NavigateOptions;
+use wasm_bindgen::JsCast;
+
+use crate::components::dialog::Dialog;
+use crate::components::hash;
+use crate::components::icon::{Icon, IconName, IconSize};
+use crate::components::theme::{Theme, ThemeContext};
+
+const RECENTS_KEY: &str = "command-palette-recents";
+const MAX_RECENTS: usize = 5;
+const MAX_RESULTS: usize = 50;
+
+/// Result groups, in display order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CommandGroup {
+    Recent,
+    Navigation,
+    Headings,
+    Memory,
+    Actions,
+}
+
+impl CommandGroup {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CommandGroup::Recent => "Recent",
+            CommandGroup::Navigation => "Pages",
+            CommandGroup::Headings => "On this page",
+            CommandGroup::Memory => "Memory",
+            CommandGroup::Actions => "Actions",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum CommandAction {
+    /// Go to a route, or scroll to `#id` on the current page.
+    Navigate(String),
+    Run(Callback<()>),
+}
+
+#[derive(Clone)]
+pub struct Command {
+    pub id: String,
+    pub label: String,
+    pub group: CommandGroup,
+    /// Extra search terms that don't appear in the label.
+    pub keywords: Vec<String>,
+    /// Key combination hint such as `"Mod+Shift+L"`; `Mod` shows as ⌘ on
+    /// macOS and Ctrl elsewhere. Display only, the palette doesn't bind it.
+    pub shortcut: Option<String>,
+    pub action: CommandAction,
+}
+
+impl Command {
+    pub fn navigate(id: impl Into<String>, label: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            group: CommandGroup::Navigation,
+            keywords: Vec::new(),
+            shortcut: None,
+            action: CommandAction::Navigate(href.into()),
+        }
+    }
+
+    pub fn action(id: impl Into<String>, label: impl Into<String>, run: Callback<()>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            group: CommandGroup::Actions,
+            keywords: Vec::new(),
+            shortcut: None,
+            action: CommandAction::Run(run),
+        }
+    }
+
+    pub fn group(mut self, group: CommandGroup) -> Self {
+        self.group = group;
+        self
+    }
+
+    pub fn keywords<S: Into<String>>(mut self, keywords: impl IntoIterator<Item = S>) -> Self {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+}
+
+/// Subsequence fuzzy match of `query` against `text`, ignoring case and
+/// whitespace in the query. Returns a score (higher is better) and the char
+/// indices of `text` that matched, or `None` if not every query char was
+/// found in order. Consecutive matches and matches at word starts score more.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut matched: Vec<usize> = Vec::with_capacity(query.len());
+    let mut score = 0;
+    let mut next = 0;
+    for (index, c) in chars.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if !c.to_lowercase().eq(query[next].to_lowercase()) {
+            continue;
+        }
+        let word_start = index == 0
+            || !chars[index - 1].is_alphanumeric()
+            || (chars[index - 1].is_lowercase() && c.is_uppercase());
+        score += 1;
+        if word_start {
+            score += 8;
+        }
+        match matched.last() {
+            Some(&last) if last + 1 == index => score += 5,
+            Some(&last) => score -= (index - last - 1).min(5) as i32,
+            None => score -= index.min(5) as i32,
+        }
+        matched.push(index);
+        next += 1;
+    }
+
+    (next == query.len()).then(|| (score - chars.len() as i32 / 10, matched))
+}
+
+fn load_recents() -> Vec<String> {
+    window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(RECENTS_KEY).ok().flatten())
+        .map(|value| value.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn save_recents(recents: &[String]) {
+    if let Some(storage) = window().local_storage().ok().flatten() {
+        let _ = storage.set_item(RECENTS_KEY, &recents.join("\n"));
+    }
+}
+
+fn is_mac() -> bool {
+    window().navigator().user_agent().is_ok_and(|agent| agent.contains("Mac"))
+}
+
+/// Registered commands and the palette's open state.
+#[derive(Clone, Copy)]
+pub struct CommandRegistry {
+    commands: RwSignal<Vec<Command>>,
+    recents: RwSignal<Vec<String>>,
+    pub open: RwSignal<bool>,
+}
+
+impl CommandRegistry {
+    fn new() -> Self {
+        Self {
+            commands: RwSignal::new(Vec::new()),
+            recents: RwSignal::new(load_recents()),
+            open: RwSignal::new(false),
+        }
+    }
+
+    /// Add `command`, replacing any with the same id.
+    pub fn register(&self, command: Command) {
+        self.commands.update(|commands| {
+            commands.retain(|existing| existing.id != command.id);
+            commands.push(command);
+        });
+    }
+
+    pub fn unregister(&self, id: &str) {
+        self.commands.try_update(|commands| commands.retain(|command| command.id != id));
+    }
+
+    /// Register `command` until the calling component is unmounted.
+    pub fn register_scoped(&self, command: Command) {
+        let id = command.id.clone();
+        self.register(command);
+        let registry = *self;
+        on_cleanup(move || registry.unregister(&id));
+    }
+
+    pub fn toggle(&self) {
+        self.open.update(|open| *open = !*open);
+    }
+
+    fn remember(&self, id: &str) {
+        self.recents.update(|recents| {
+            recents.retain(|recent| recent != id);
+            recents.insert(0, id.to_string());
+            recents.truncate(MAX_RECENTS);
+            save_recents(recents);
+        });
+    }
+}
+
+pub fn use_commands() -> CommandRegistry {
+    expect_context::<CommandRegistry>()
+}
+
+/// A command that matched the query.
+#[derive(Clone)]
+struct Match {
+    command: Command,
+    group: CommandGroup,
+    /// Char indices of the label to highlight.
+    highlight: Vec<usize>,
+}
+
+/// Actions aren't comparable; a match is unchanged if it shows the same
+/// command the same way.
+impl PartialEq for Match {
+    fn eq(&self, other: &Self) -> bool {
+        self.command.id == other.command.id
+            && self.command.label == other.command.label
+            && self.group == other.group
+            && self.highlight == other.highlight
+    }
+}
+
+/// Best score of `query` against the label or any keyword; only label
+/// matches are highlighted.
+fn score(query: &str, command: &Command) -> Option<(i32, Vec<usize>)> {
+    let label = fuzzy_match(query, &command.label);
+    let keyword = command
+        .keywords
+        .iter()
+        .filter_map(|keyword| fuzzy_match(query, keyword))
+        .map(|(score, _)| (score - 2, Vec::new()))
+        .max_by_key(|(score, _)| *score);
+    match (label, keyword) {
+        (Some(label), Some(keyword)) if keyword.0 > label.0 => Some(keyword),
+        (Some(label), _) => Some(label),
+        (None, keyword) => keyword,
+    }
+}
+
+/// Headings with ids in the page content, as commands that scroll to them.
+fn page_headings() -> Vec<Command> {
+    let Ok(nodes) = document().query_selector_all("main h1[id], main h2[id], main h3[id], main h4[id]") else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+        .filter_map(|heading| {
+            let label = heading.text_content()?.trim().to_string();
+            (!label.is_empty()).then(|| {
+                let id = heading.id();
+                Command::navigate(format!("heading.{}", id), label, format!("#{}", id)).group(CommandGroup::Headings)
+            })
+        })
+        .collect()
+}
+
+/// Provides the [`CommandRegistry`] and renders the palette.
+///
+/// Must sit inside the `Router`. `commands` seeds the registry (typically
+/// the site's routes); theme actions are added when a `ThemeProvider` is
+/// above it.
+#[component]
+pub fn CommandProvider(#[prop(optional)] commands: Vec<Command>, children: Children) -> impl IntoView {
+    let registry = CommandRegistry::new();
+    provide_context(registry);
+    for command in commands {
+        registry.register(command);
+    }
+
+    if let Some(theme) = use_context::<ThemeContext>() {
+        let signal = theme.theme;
+        let set_theme = move |theme_value: Theme| Callback::new(move |_| signal.set(theme_value));
+        registry.register(
+            Command::action("theme.toggle", "Toggle light/dark theme", Callback::new(move |_| theme.toggle()))
+                .keywords(["theme", "mode", "appearance"]),
+        );
+        for (theme_value, label) in [
+            (Theme::Light, "Switch to light theme"),
+            (Theme::Dark, "Switch to dark theme"),
+            (Theme::HighContrast, "Switch to high-contrast theme"),
+        ] {
+            registry.register(
+                Command::action(format!("theme.{}", theme_value.as_str()), label, set_theme(theme_value))
+                    .keywords(["theme", "appearance", theme_value.as_str()]),
+            );
+        }
+    }
+
+    let shortcut = window_event_listener(ev::keydown, move |ev| {
+        if (ev.ctrl_key() || ev.meta_key()) && ev.key().eq_ignore_ascii_case("k") {
+            ev.prevent_default();
+            registry.toggle();
+        }
+    });
+    on_cleanup(move || shortcut.remove());
+
+    view! {
+        {children()}
+        <CommandPalette registry=registry />
+    }
+}
+
+/// Shortcut hint as a row of `<kbd>`s.
+#[component]
+fn Shortcut(#[prop(into)] keys: String) -> impl IntoView {
+    let mac = is_mac();
+    view! {
+        <span class="flex gap-1" aria-hidden="true">
+            {keys
+                .split('+')
+                .map(|key| {
+                    let key = match key {
+                        "Mod" if mac => "⌘",
+                        "Mod" => "Ctrl",
+                        "Shift" if mac => "⇧",
+                        "Alt" if mac => "⌥",
+                        key => key,
+                    };
+                    view! {
+                        <kbd class="min-w-[1.5rem] px-1.5 py-0.5 rounded border border-border bg-surface text-center text-xs font-mono text-text-muted">
+                            {key.to_string()}
+                        </kbd>
+                    }
+                })
+                .collect_view()}
+        </span>
+    }
+}
+
+/// Button that opens the palette, showing its shortcut.
+#[component]
+pub fn CommandPaletteButton(#[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    let registry = use_commands();
+    view! {
+        <button
+            type="button"
+            class=format!(
+                "flex items-center gap-3 px-3 py-2 rounded-md border border-border bg-surface text-sm text-text-muted hover:text-text-primary hover:border-accent transition-colors focus:outline-none focus-visible:ring-2 focus-visible:ring-accent {}",
+                class.unwrap_or_default()
+            )
+            aria-keyshortcuts="Control+K Meta+K"
+            on:click=move |_| registry.open.set(true)
+        >
+            <Icon name=IconName::Command size=IconSize::Small />
+            <span>"Search…"</span>
+            <Shortcut keys="Mod+K" />
+        </button>
+    }
+}
+
+#[component]
+fn CommandPalette(registry: CommandRegistry) -> impl IntoView {
+    let navigate = use_navigate();
+    let query = RwSignal::new(String::new());
+    let active = RwSignal::new(0usize);
+    let headings = RwSignal::new(Vec::<Command>::new());
+
+    // Fresh state on every open; headings depend on the page being shown.
+    Effect::new(move |_| {
+        if registry.open.get() {
+            query.set(String::new());
+            headings.set(page_headings());
+        }
+    });
+
+    let results = Memo::new(move |_| {
+        let query = query.get();
+        let mut commands = registry.commands.get();
+        commands.extend(headings.get());
+
+        let mut matches: Vec<(i32, Match)> = if query.trim().is_empty() {
+            // No query: recents first, then everything else in group order.
+            let recents = registry.recents.get();
+            let mut recent: Vec<(i32, Match)> = recents
+                .iter()
+                .filter_map(|id| commands.iter().find(|command| command.id == *id))
+                .map(|command| (0, Match { command: command.clone(), group: CommandGroup::Recent, highlight: Vec::new() }))
+                .collect();
+            let rest = commands
+                .iter()
+                .filter(|command| !recents.contains(&command.id))
+                .map(|command| (0, Match { command: command.clone(), group: command.group, highlight: Vec::new() }));
+            recent.extend(rest);
+            recent
+        } else {
+            commands
+                .iter()
+                .filter_map(|command| {
+                    score(&query, command).map(|(score, highlight)| {
+                        (score, Match { command: command.clone(), group: command.group, highlight })
+                    })
+                })
+                .collect()
+        };
+        // Stable, so the no-query order is kept within groups.
+        matches.sort_by(|(a_score, a), (b_score, b)| a.group.cmp(&b.group).then(b_score.cmp(a_score)));
+        matches.truncate(MAX_RESULTS);
+        matches.into_iter().map(|(_, found)| found).collect::<Vec<_>>()
+    });
+    Effect::new(move |_| {
+        query.track();
+        active.set(0);
+    });
+
+    let run = move |command: Command| {
+        registry.open.set(false);
+        registry.remember(&command.id);
+        match command.action {
+            CommandAction::Navigate(href) => match href.strip_prefix('#') {
+                Some(id) => {
+                    if let Some(target) = document().get_element_by_id(id) {
+                        target.scroll_into_view();
+                    }
+                    hash::replace(Some(id));
+                }
+                None => navigate(&href, NavigateOptions::default()),
+            },
+            CommandAction::Run(callback) => callback.run(()),
+        }
+    };
+    let run = StoredValue::new(run);
+
+    let option_id = |index: usize| format!("command-palette-option-{}", index);
+    let scroll_active = move |index: usize| {
+        if let Some(option) = document().get_element_by_id(&option_id(index)) {
+            option.scroll_into_view_with_bool(false);
+        }
+    };
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let count = results.with_untracked(Vec::len);
+        if count == 0 {
+            return;
+        }
+        let next = match ev.key().as_str() {
+            "ArrowDown" => (active.get_untracked() + 1) % count,
+            "ArrowUp" => (active.get_untracked() + count - 1) % count,
+            "Home" if ev.ctrl_key() => 0,
+            "End" if ev.ctrl_key() => count - 1,
+            "Enter" => {
+                ev.prevent_default();
+                if let Some(command) = results.with_untracked(|results| results.get(active.get_untracked()).map(|found| found.command.clone())) {
+                    run.with_value(|run| run(command));
+                }
+                return;
+            }
+            _ => return,
+        };
+        ev.prevent_default();
+        active.set(next);
+        scroll_active(next);
+    };
+
+    let grouped = move || {
+        let mut groups: Vec<(CommandGroup, Vec<(usize, Match)>)> = Vec::new();
+        for (index, found) in results.get().into_iter().enumerate() {
+            match groups.last_mut() {
+                Some((group, items)) if *group == found.group => items.push((index, found)),
+                _ => groups.push((found.group, vec![(index, found)])),
+            }
+        }
+        groups
+    };
+
+    let highlighted = |label: &str, highlight: &[usize]| {
+        label
+            .chars()
+            .enumerate()
+            .map(|(index, c)| {
+                if highlight.contains(&index) {
+                    view! { <mark class="bg-transparent text-accent font-semibold">{c.to_string()}</mark> }.into_any()
+                } else {
+                    c.to_string().into_any()
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <Dialog open=registry.open title="Command palette" initial_focus="input">
+            <div class="pb-3">
+                <input
+                    type="text"
+                    class="input w-full"
+                    placeholder="Search pages, sections and actions…"
+                    role="combobox"
+                    aria-expanded="true"
+                    aria-controls="command-palette-results"
+                    aria-autocomplete="list"
+                    aria-activedescendant=move || (!results.with(Vec::is_empty)).then(|| option_id(active.get()))
+                    autocomplete="off"
+                    spellcheck="false"
+                    prop:value=move || query.get()
+                    on:input=move |ev| query.set(event_target_value(&ev))
+                    on:keydown=on_keydown
+                />
+            </div>
+            <div id="command-palette-results" role="listbox" aria-label="Commands" class="max-h-[50vh] overflow-y-auto -mx-2 pb-2">
+                {move || {
+                    grouped()
+                        .into_iter()
+                        .map(|(group, items)| {
+                            let label_id = format!("command-palette-group-{:?}", group).to_lowercase();
+                            view! {
+                                <div role="group" aria-labelledby=label_id.clone() class="py-1">
+                                    <div id=label_id.clone() class="px-2 py-1 text-xs font-orbitron uppercase tracking-wider text-text-muted">
+                                        {group.label()}
+                                    </div>
+                                    {items
+                                        .into_iter()
+                                        .map(|(index, found)| {
+                                            let command = found.command.clone();
+                                            view! {
+                                                <div
+                                                    id=option_id(index)
+                                                    role="option"
+                                                    aria-selected=move || (active.get() == index).to_string()
+                                                    class="flex items-center justify-between gap-4 px-3 py-2 rounded-md cursor-pointer font-inter text-sm text-text-primary"
+                                                    class=("bg-accent/15", move || active.get() == index)
+                                                    class=("shadow-[inset_2px_0_0_theme(colors.accent)]", move || active.get() == index)
+                                                    on:mousemove=move |_| {
+                                                        if active.get_untracked() != index {
+                                                            active.set(index);
+                                                        }
+                                                    }
+                                                    on:click=move |_| run.with_value(|run| run(command.clone()))
+                                                >
+                                                    <span class="truncate">{highlighted(&found.command.label, &found.highlight)}</span>
+                                                    {found.command.shortcut.clone().map(|keys| view! { <Shortcut keys=keys /> })}
+                                                </div>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                }}
+                <Show when=move || results.with(Vec::is_empty)>
+                    <p class="px-3 py-6 text-center text-sm text-text-muted">"No matching commands."</p>
+                </Show>
+            </div>
+            <div class="flex flex-wrap gap-4 pt-3 border-t border-border text-xs text-text-muted" aria-hidden="true">
+                <span class="flex items-center gap-1"><Shortcut keys="↑+↓" />" to move"</span>
+                <span class="flex items-center gap-1"><Shortcut keys="↵" />" to run"</span>
+                <span class="flex items-center gap-1"><Shortcut keys="Esc" />" to close"</span>
+            </div>
+        </Dialog>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(query: &str, text: &str) -> i32 {
+        fuzzy_match(query, text).unwrap_or_else(|| panic!("{query:?} should match {text:?}")).0
+    }
+
+    #[test]
+    fn matches_subsequences_ignoring_case_and_query_spaces() {
+        assert_eq!(fuzzy_match("", "Anything"), Some((0, Vec::new())));
+        assert_eq!(fuzzy_match("  ", "Anything").map(|(_, indices)| indices), Some(Vec::new()));
+        assert_eq!(fuzzy_match("tch", "Technology").map(|(_, indices)| indices), Some(vec![0, 2, 3]));
+        // Greedy: each query char takes its first occurrence.
+        assert_eq!(fuzzy_match("DS", "design system").map(|(_, indices)| indices), Some(vec![0, 2]));
+        assert_eq!(fuzzy_match("go to", "Go to page").map(|(_, indices)| indices), Some(vec![0, 1, 3, 4]));
+        assert_eq!(fuzzy_match("xyz", "Technology"), None);
+        assert_eq!(fuzzy_match("ygolonhcet", "Technology"), None);
+    }
+
+    #[test]
+    fn highlights_char_indices_in_multi_byte_text() {
+        assert_eq!(fuzzy_match("cafe", "Café"), None);
+        assert_eq!(fuzzy_match("caf", "Ünï café").map(|(_, indices)| indices), Some(vec![4, 5, 6]));
+    }
+
+    #[test]
+    fn word_starts_beat_mid_word_matches() {
+        assert!(score("cp", "Command Palette") > score("cp", "Compact"));
+        // camelCase humps count as word starts.
+        assert!(score("sw", "ThemeSwitcher") > score("sw", "Answer"));
+    }
+
+    #[test]
+    fn consecutive_matches_beat_gaps() {
+        assert!(score("tech", "Technology") > score("tech", "The Cheat"));
+        assert!(score("abc", "abcd") > score("abc", "axbxc"));
+        // Gaps cost at most 5 each, however long.
+        assert_eq!(score("ab", "a______b"), score("ab", "a_______b"));
+    }
+
+    #[test]
+    fn shorter_texts_win_ties() {
+        assert!(score("home", "Home") > score("home", "Home of the Memory Bank project"));
+    }
+}
@@ -0,0 +1,729 @@
+//! Generic client-side data table.
+//!
+//! Columns are typed against the row type: each one extracts a [`CellValue`]
+//! used for sorting, filtering and CSV export, and can optionally render the
+//! cell itself. The pipeline is filter, then sort, then paginate; "the
+//! current view" (what CSV export writes out) is the filtered, sorted rows
+//! across all pages. Below the `md` breakpoint rows render as stacked cards.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// A cell's plain value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellValue {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Empty,
+}
+
+impl CellValue {
+    /// Order for sorting; empty cells sort last in either direction, which
+    /// callers handle separately.
+    fn compare(&self, other: &CellValue) -> Ordering {
+        match (self, other) {
+            (CellValue::Number(a), CellValue::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (CellValue::Bool(a), CellValue::Bool(b)) => a.cmp(b),
+            (a, b) => a.to_string().to_lowercase().cmp(&b.to_string().to_lowercase()),
+        }
+    }
+
+    /// Whether the cell passes `filter`. Numbers accept a comparison
+    /// (`>10`, `<=2.5`, `=3`); everything else is a case-insensitive
+    /// substring match.
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.trim();
+        if filter.is_empty() {
+            return true;
+        }
+        if let CellValue::Number(value) = self {
+            for (op, test) in [
+                (">=", (|a, b| a >= b) as fn(f64, f64) -> bool),
+                ("<=", |a, b| a <= b),
+                (">", |a, b| a > b),
+                ("<", |a, b| a < b),
+                ("=", |a, b| a == b),
+            ] {
+                if let Some(rest) = filter.strip_prefix(op) {
+                    return rest.trim().parse::<f64>().is_ok_and(|bound| test(*value, bound));
+                }
+            }
+        }
+        self.to_string().to_lowercase().contains(&filter.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for CellValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CellValue::Text(text) => f.write_str(text),
+            CellValue::Number(number) => write!(f, "{}", number),
+            CellValue::Bool(true) => f.write_str("Yes"),
+            CellValue::Bool(false) => f.write_str("No"),
+            CellValue::Empty => Ok(()),
+        }
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        CellValue::Text(value)
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        CellValue::Text(value.to_string())
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        CellValue::Number(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(CellValue::Empty)
+    }
+}
+
+type ValueFn<T> = Arc<dyn Fn(&T) -> CellValue + Send + Sync>;
+type RenderFn<T> = Arc<dyn Fn(&T) -> AnyView + Send + Sync>;
+
+/// A typed column definition.
+pub struct Column<T> {
+    header: String,
+    value: ValueFn<T>,
+    render: Option<RenderFn<T>>,
+    sortable: bool,
+    filterable: bool,
+    numeric: bool,
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            value: self.value.clone(),
+            render: self.render.clone(),
+            sortable: self.sortable,
+            filterable: self.filterable,
+            numeric: self.numeric,
+        }
+    }
+}
+
+impl<T> Column<T> {
+    /// A sortable, filterable column showing `value`.
+    pub fn new<V: Into<CellValue>>(header: impl Into<String>, value: impl Fn(&T) -> V + Send + Sync + 'static) -> Self {
+        Self {
+            header: header.into(),
+            value: Arc::new(move |row| value(row).into()),
+            render: None,
+            sortable: true,
+            filterable: true,
+            numeric: false,
+        }
+    }
+
+    /// A right-aligned numeric column, with comparison filters.
+    pub fn number(header: impl Into<String>, value: impl Fn(&T) -> f64 + Send + Sync + 'static) -> Self {
+        Self { numeric: true, ..Self::new(header, move |row: &T| CellValue::Number(value(row))) }
+    }
+
+    /// Render cells with `render` instead of the plain value. Sorting,
+    /// filtering and export still use the value.
+    pub fn render(mut self, render: impl Fn(&T) -> AnyView + Send + Sync + 'static) -> Self {
+        self.render = Some(Arc::new(render));
+        self
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    fn cell(&self, row: &T) -> AnyView {
+        match &self.render {
+            Some(render) => render(row),
+            None => (self.value)(row).to_string().into_any(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    fn aria(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+/// Escape a CSV field per RFC 4180. Fields that a spreadsheet would run as a
+/// formula get a leading `'`.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) && value.parse::<f64>().is_err() {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// `rows` as CSV, one column per definition, with a header line.
+pub fn to_csv<T>(columns: &[Column<T>], rows: &[T]) -> String {
+    let line = |fields: Vec<String>| fields.iter().map(|field| csv_field(field)).collect::<Vec<_>>().join(",");
+    let mut lines = vec![line(columns.iter().map(|column| column.header.clone()).collect())];
+    lines.extend(rows.iter().map(|row| line(columns.iter().map(|column| (column.value)(row).to_string()).collect())));
+    lines.join("\r\n") + "\r\n"
+}
+
+/// Offer `contents` as a file download.
+fn download(filename: &str, contents: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("text/csv;charset=utf-8");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let link = document().create_element("a")?.dyn_into::<web_sys::HtmlAnchorElement>()?;
+    link.set_href(&url);
+    link.set_download(filename);
+    link.click();
+    // Some browsers start the download after `click` returns; revoking the
+    // URL right away can cancel it.
+    set_timeout(
+        move || {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        },
+        Duration::from_secs(30),
+    );
+    Ok(())
+}
+
+/// Sortable, filterable, paginated table over `rows`.
+///
+/// `row_key` identifies rows for selection; selected keys are kept in
+/// `selection` (pass one in to read them) and survive sorting, filtering and
+/// paging. `page_size` of 0 disables pagination. Headers stick to the top
+/// of the table's scroll container, capped at `max_height` when given.
+#[component]
+pub fn DataTable<T>(
+    #[prop(into)] rows: Signal<Vec<T>>,
+    columns: Vec<Column<T>>,
+    row_key: impl Fn(&T) -> String + Send + Sync + 'static,
+    /// Accessible name for the table.
+    #[prop(into)] caption: String,
+    #[prop(default = 10)] page_size: usize,
+    #[prop(optional)] selectable: bool,
+    #[prop(optional)] selection: Option<RwSignal<Vec<String>>>,
+    /// Filename for CSV export; no export button without it.
+    #[prop(optional, into)] export_filename: Option<String>,
+    #[prop(optional, into)] max_height: Option<String>,
+    #[prop(into, default = "No matching rows.".to_string())] empty_text: String,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let selectable = selectable || selection.is_some();
+    let selection = selection.unwrap_or_else(|| RwSignal::new(Vec::new()));
+    let column_count = columns.len();
+    let has_filters = columns.iter().any(|column| column.filterable);
+    let columns = StoredValue::new(columns);
+    let row_key = StoredValue::new(Arc::new(row_key) as Arc<dyn Fn(&T) -> String + Send + Sync>);
+
+    let sort = RwSignal::new(None::<(usize, SortDirection)>);
+    let filters = RwSignal::new(vec![String::new(); column_count]);
+    let page = RwSignal::new(0usize);
+
+    // Indices into `rows` of the filtered, sorted rows: the current view.
+    let view_indices = Memo::new(move |_| {
+        let filters = filters.get();
+        let sort = sort.get();
+        columns.with_value(|columns| {
+            let mut view: Vec<(Vec<CellValue>, usize)> = rows.with(|rows| {
+                rows.iter()
+                    .enumerate()
+                    .map(|(index, row)| (columns.iter().map(|column| (column.value)(row)).collect::<Vec<_>>(), index))
+                    .filter(|(values, _)| values.iter().zip(&filters).all(|(value, filter)| value.matches(filter)))
+                    .collect()
+            });
+            if let Some((index, direction)) = sort {
+                view.sort_by(|(a, _), (b, _)| match (&a[index], &b[index]) {
+                    (CellValue::Empty, CellValue::Empty) => Ordering::Equal,
+                    (CellValue::Empty, _) => Ordering::Greater,
+                    (_, CellValue::Empty) => Ordering::Less,
+                    (a, b) => match direction {
+                        SortDirection::Ascending => a.compare(b),
+                        SortDirection::Descending => b.compare(a),
+                    },
+                });
+            }
+            view.into_iter().map(|(_, index)| index).collect::<Vec<_>>()
+        })
+    });
+    let view_rows = move |range: std::ops::Range<usize>| {
+        rows.with(|rows| view_indices.with(|indices| indices[range].iter().map(|index| rows[*index].clone()).collect::<Vec<_>>()))
+    };
+    let total = move || view_indices.with(Vec::len);
+    let page_count = move || if page_size == 0 { 1 } else { total().div_ceil(page_size).max(1) };
+    let page_range = move || {
+        if page_size == 0 {
+            return (0, total());
+        }
+        let start = page.get().min(page_count() - 1) * page_size;
+        (start, (start + page_size).min(total()))
+    };
+    let page_rows = move || {
+        let (start, end) = page_range();
+        view_rows(start..end)
+    };
+
+    // Back to the first page whenever the view changes shape.
+    Effect::new(move |_| {
+        filters.track();
+        sort.track();
+        page.set(0);
+    });
+
+    let key_of = move |row: &T| row_key.with_value(|key| key(row));
+    let is_selected = move |key: &str| selection.with(|selection| selection.iter().any(|selected| selected == key));
+    let toggle_row = move |key: String| {
+        selection.update(|selection| {
+            if let Some(index) = selection.iter().position(|selected| *selected == key) {
+                selection.remove(index);
+            } else {
+                selection.push(key);
+            }
+        })
+    };
+    // The header checkbox covers every row in the current view.
+    let view_keys = move || rows.with(|rows| view_indices.with(|indices| indices.iter().map(|index| key_of(&rows[*index])).collect::<Vec<_>>()));
+    let all_selected = move || {
+        let keys = view_keys();
+        !keys.is_empty() && keys.iter().all(|key| is_selected(key))
+    };
+    let some_selected = move || view_keys().iter().any(|key| is_selected(key));
+    let toggle_all = move |_| {
+        let keys = view_keys();
+        if all_selected() {
+            selection.update(|selection| selection.retain(|selected| !keys.contains(selected)));
+        } else {
+            selection.update(|selection| {
+                for key in keys {
+                    if !selection.contains(&key) {
+                        selection.push(key);
+                    }
+                }
+            });
+        }
+    };
+
+    let cycle_sort = move |index: usize| {
+        sort.update(|sort| {
+            *sort = match *sort {
+                Some((current, SortDirection::Ascending)) if current == index => Some((index, SortDirection::Descending)),
+                Some((current, SortDirection::Descending)) if current == index => None,
+                _ => Some((index, SortDirection::Ascending)),
+            }
+        })
+    };
+
+    let export = {
+        let filename = export_filename.clone().unwrap_or_default();
+        move |_: ev::MouseEvent| {
+            let view = untrack(|| view_rows(0..total()));
+            let csv = columns.with_value(|columns| to_csv(columns, &view));
+            if let Err(err) = download(&filename, &csv) {
+                leptos::logging::error!("CSV export failed: {:?}", err);
+            }
+        }
+    };
+
+    let filter_input = move |index: usize, header: String| {
+        view! {
+            <input
+                type="search"
+                class="input input-sm w-full font-normal"
+                placeholder="Filter"
+                aria-label=format!("Filter {}", header)
+                prop:value=move || filters.with(|filters| filters[index].clone())
+                on:input=move |ev| filters.update(|filters| filters[index] = event_target_value(&ev))
+            />
+        }
+    };
+
+    // Named after the row's first cell, or its key when that is empty.
+    let select_cell = move |row: &T| {
+        let key = key_of(row);
+        let checked_key = key.clone();
+        let name = columns
+            .with_value(|columns| columns.first().map(|column| (column.value)(row).to_string()))
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| key.clone());
+        view! {
+            <input
+                type="checkbox"
+                class="w-4 h-4 accent-[theme(colors.accent)]"
+                aria-label=format!("Select {}", name)
+                prop:checked=move || is_selected(&checked_key)
+                on:change=move |_| toggle_row(key.clone())
+            />
+        }
+    };
+
+    let header_cells = move || {
+        columns.with_value(|columns| {
+            columns
+                .iter()
+                .enumerate()
+                .map(|(index, column)| {
+                    let header = column.header.clone();
+                    let aria_sort = move || match sort.get() {
+                        Some((current, direction)) if current == index => Some(direction.aria()),
+                        _ => None,
+                    };
+                    let align = if column.numeric { "text-right" } else { "text-left" };
+                    let content = if column.sortable {
+                        view! {
+                            <button
+                                type="button"
+                                class="inline-flex items-center gap-1 hover:text-accent focus:outline-none focus-visible:ring-2 focus-visible:ring-accent rounded"
+                                on:click=move |_| cycle_sort(index)
+                            >
+                                {header}
+                                <span class="text-text-muted" aria-hidden="true">
+                                    {move || match aria_sort() {
+                                        Some("ascending") => "▲",
+                                        Some(_) => "▼",
+                                        None => "↕",
+                                    }}
+                                </span>
+                            </button>
+                        }
+                        .into_any()
+                    } else {
+                        header.into_any()
+                    };
+                    view! {
+                        <th scope="col" class=format!("px-4 py-3 font-outfit font-semibold {}", align) aria-sort=aria_sort>
+                            {content}
+                        </th>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    let filter_cells = move || {
+        columns.with_value(|columns| {
+            columns
+                .iter()
+                .enumerate()
+                .map(|(index, column)| {
+                    let input = column.filterable.then(|| filter_input(index, column.header.clone()));
+                    view! { <th class="px-4 pb-3 font-normal">{input}</th> }
+                })
+                .collect_view()
+        })
+    };
+
+    let table_rows = move || {
+        page_rows()
+            .into_iter()
+            .map(|row| {
+                let selected_key = key_of(&row);
+                let cells = columns.with_value(|columns| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            let align = if column.numeric { "text-right tabular-nums" } else { "" };
+                            view! { <td class=format!("px-4 py-3 {}", align)>{column.cell(&row)}</td> }
+                        })
+                        .collect_view()
+                });
+                view! {
+                    <tr
+                        class="border-t border-border hover:bg-base01/50 transition-colors"
+                        class=("bg-accent/10", move || is_selected(&selected_key))
+                    >
+                        {selectable.then(|| view! { <td class="px-4 py-3">{select_cell(&row)}</td> })}
+                        {cells}
+                    </tr>
+                }
+            })
+            .collect_view()
+    };
+
+    let cards = move || {
+        page_rows()
+            .into_iter()
+            .map(|row| {
+                let key = key_of(&row);
+                let fields = columns.with_value(|columns| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            view! {
+                                <dt class="text-text-muted font-medium">{column.header.clone()}</dt>
+                                <dd class="text-text-primary min-w-0 break-words">{column.cell(&row)}</dd>
+                            }
+                        })
+                        .collect_view()
+                });
+                view! {
+                    <li
+                        class="rounded-lg border border-border bg-surface p-4"
+                        class=("border-accent", move || is_selected(&key))
+                    >
+                        {selectable.then(|| view! {
+                            <div class="mb-2 flex justify-end">{select_cell(&row)}</div>
+                        })}
+                        <dl class="grid grid-cols-[auto_1fr] gap-x-4 gap-y-1 text-sm">{fields}</dl>
+                    </li>
+                }
+            })
+            .collect_view()
+    };
+
+    // Below `md` there are no header buttons, so sorting gets a select.
+    let sort_options = columns.with_value(|columns| {
+        columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.sortable)
+            .flat_map(|(index, column)| {
+                [(SortDirection::Ascending, "↑"), (SortDirection::Descending, "↓")].map(|(direction, arrow)| {
+                    let value = format!("{}:{}", index, direction.aria());
+                    view! { <option value=value>{format!("{} {}", column.header, arrow)}</option> }
+                })
+            })
+            .collect_view()
+    });
+    // Kept in sync with header clicks, which the select doesn't see.
+    let sort_value = move || sort.get().map(|(index, direction)| format!("{}:{}", index, direction.aria())).unwrap_or_default();
+    let mobile_sort = move |ev: ev::Event| {
+        let value = event_target_value(&ev);
+        sort.set(value.split_once(':').and_then(|(index, direction)| {
+            let direction = if direction == "descending" { SortDirection::Descending } else { SortDirection::Ascending };
+            index.parse().ok().map(|index| (index, direction))
+        }));
+    };
+
+    let card_label = caption.clone();
+    let card_empty_text = empty_text.clone();
+
+    let summary = move || {
+        let (start, end) = page_range();
+        if total() == 0 {
+            "No rows".to_string()
+        } else {
+            format!("Showing {}–{} of {}", start + 1, end, total())
+        }
+    };
+
+    view! {
+        <div class=format!("space-y-3 {}", class.unwrap_or_default())>
+            <div class="flex flex-wrap items-center justify-between gap-3">
+                <p class="text-sm font-inter text-text-secondary" aria-live="polite">
+                    {summary}
+                    {move || (selectable && !selection.with(Vec::is_empty)).then(|| format!(" · {} selected", selection.with(Vec::len)))}
+                </p>
+                <div class="flex items-center gap-2">
+                    <select class="input input-sm md:hidden" aria-label="Sort by" prop:value=sort_value on:change=mobile_sort>
+                        <option value="">"Unsorted"</option>
+                        {sort_options}
+                    </select>
+                    {export_filename.map(|_| view! {
+                        <button type="button" class="btn-secondary btn-sm" on:click=export>"Export CSV"</button>
+                    })}
+                </div>
+            </div>
+
+            {has_filters.then(|| view! {
+                <details class="md:hidden">
+                    <summary class="cursor-pointer text-sm font-outfit text-text-secondary">"Filters"</summary>
+                    <div class="mt-2 grid gap-2">
+                        {columns.with_value(|columns| {
+                            columns
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, column)| column.filterable)
+                                .map(|(index, column)| filter_input(index, column.header.clone()))
+                                .collect_view()
+                        })}
+                    </div>
+                </details>
+            })}
+
+            <div
+                class="hidden md:block overflow-auto rounded-lg border border-border"
+                style=max_height.map(|height| format!("max-height: {}", height))
+            >
+                <table class="w-full text-sm font-inter text-text-primary border-collapse">
+                    <caption class="sr-only">{caption}</caption>
+                    <thead class="sticky top-0 z-10 bg-surface-elevated text-text-secondary shadow-[0_1px_0_theme(colors.border)]">
+                        <tr>
+                            {selectable.then(|| view! {
+                                <th scope="col" class="px-4 py-3 w-10">
+                                    <input
+                                        type="checkbox"
+                                        class="w-4 h-4 accent-[theme(colors.accent)]"
+                                        aria-label="Select all rows"
+                                        prop:checked=all_selected
+                                        prop:indeterminate=move || some_selected() && !all_selected()
+                                        on:change=toggle_all
+                                    />
+                                </th>
+                            })}
+                            {header_cells}
+                        </tr>
+                        {has_filters.then(|| view! {
+                            <tr>
+                                {selectable.then(|| view! { <th></th> })}
+                                {filter_cells}
+                            </tr>
+                        })}
+                    </thead>
+                    <tbody>
+                        {table_rows}
+                        <Show when=move || total() == 0>
+                            <tr>
+                                <td colspan=column_count + usize::from(selectable) class="px-4 py-8 text-center text-text-muted">
+                                    {empty_text.clone()}
+                                </td>
+                            </tr>
+                        </Show>
+                    </tbody>
+                </table>
+            </div>
+
+            <ul class="md:hidden space-y-3" aria-label=card_label>
+                {cards}
+            </ul>
+            <Show when=move || total() == 0>
+                <p class="md:hidden py-8 text-center text-sm text-text-muted">{card_empty_text.clone()}</p>
+            </Show>
+
+            <Show when=move || { page_count() > 1 }>
+                <nav class="flex items-center justify-end gap-2 text-sm" aria-label="Pagination">
+                    <button
+                        type="button"
+                        class="btn-text btn-sm"
+                        disabled=move || page.get() == 0
+                        on:click=move |_| page.update(|page| *page = page.saturating_sub(1))
+                    >
+                        "Previous"
+                    </button>
+                    <span class="font-inter text-text-secondary">
+                        {move || format!("Page {} of {}", page.get().min(page_count() - 1) + 1, page_count())}
+                    </span>
+                    <button
+                        type="button"
+                        class="btn-text btn-sm"
+                        disabled=move || page.get() + 1 >= page_count()
+                        on:click=move |_| page.update(|page| *page += 1)
+                    >
+                        "Next"
+                    </button>
+                </nav>
+            </Show>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn csv_field_defuses_formulas_but_not_numbers() {
+        assert_eq!(csv_field("=SUM(A1)"), "'=SUM(A1)");
+        assert_eq!(csv_field("+cmd"), "'+cmd");
+        assert_eq!(csv_field("@user"), "'@user");
+        assert_eq!(csv_field("-1.5"), "-1.5");
+        assert_eq!(csv_field("=1,2"), "\"'=1,2\"");
+    }
+
+    #[test]
+    fn to_csv_writes_header_and_rows_with_crlf() {
+        let columns = vec![
+            Column::new("Name", |row: &(&str, Option<f64>)| row.0),
+            Column::number("Score", |row: &(&str, Option<f64>)| row.1.unwrap_or(0.0)),
+            Column::new("Note", |row: &(&str, Option<f64>)| row.1.map(|_| "scored")),
+        ];
+        let rows = [("Ada, Countess", Some(2.5)), ("Bob", None)];
+        assert_eq!(to_csv(&columns, &rows), "Name,Score,Note\r\n\"Ada, Countess\",2.5,scored\r\nBob,0,\r\n");
+        assert_eq!(to_csv(&columns, &[]), "Name,Score,Note\r\n");
+    }
+
+    #[test]
+    fn matches_substrings_case_insensitively() {
+        let text = CellValue::from("Memory Bank");
+        assert!(text.matches(""));
+        assert!(text.matches("  "));
+        assert!(text.matches("bank"));
+        assert!(text.matches(" MEMORY "));
+        assert!(!text.matches("graph"));
+        assert!(CellValue::Bool(true).matches("yes"));
+        assert!(!CellValue::Empty.matches("x"));
+        // Comparisons only apply to numbers.
+        assert!(!text.matches(">1"));
+    }
+
+    #[test]
+    fn matches_numbers_by_comparison() {
+        let number = CellValue::Number(10.0);
+        assert!(number.matches(">5"));
+        assert!(number.matches(">=10"));
+        assert!(!number.matches(">10"));
+        assert!(number.matches("<= 10"));
+        assert!(number.matches("<10.5"));
+        assert!(number.matches("=10"));
+        assert!(!number.matches("=9"));
+        assert!(!number.matches(">abc"));
+        assert!(number.matches("10"));
+        assert!(CellValue::Number(110.0).matches("10"));
+    }
+}
@@ -0,0 +1,265 @@
+use std::cell::RefCell;
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::html;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+
+use crate::components::focus;
+use crate::components::ids::next_id;
+
+/// Matches the `duration-200` transition on the backdrop and panel.
+const TRANSITION: Duration = Duration::from_millis(200);
+
+/// Ids of open dialogs, innermost last, plus the body styles replaced by the
+/// scroll lock while any dialog is open.
+struct DialogStack {
+    open: Vec<usize>,
+    saved: Option<(String, String)>,
+}
+
+thread_local! {
+    static STACK: RefCell<DialogStack> = const { RefCell::new(DialogStack { open: Vec::new(), saved: None }) };
+}
+
+/// Push dialog `id` and return its depth; the first dialog locks page scroll.
+fn stack_push(id: usize) -> usize {
+    STACK.with(|stack| {
+        let DialogStack { open, saved } = &mut *stack.borrow_mut();
+        if open.is_empty() {
+            *saved = lock_scroll();
+        }
+        open.push(id);
+        open.len()
+    })
+}
+
+/// Remove dialog `id`; the last one out restores page scroll.
+fn stack_remove(id: usize) {
+    STACK.with(|stack| {
+        let DialogStack { open, saved } = &mut *stack.borrow_mut();
+        let was_open = !open.is_empty();
+        open.retain(|open| *open != id);
+        if was_open && open.is_empty() {
+            if let Some(saved) = saved.take() {
+                unlock_scroll(saved);
+            }
+        }
+    })
+}
+
+/// Hide body overflow, padding for the scrollbar so the page doesn't shift.
+/// Returns the previous `overflow` and `padding-right`.
+fn lock_scroll() -> Option<(String, String)> {
+    let body = document().body()?;
+    let style = body.style();
+    let saved = (
+        style.get_property_value("overflow").unwrap_or_default(),
+        style.get_property_value("padding-right").unwrap_or_default(),
+    );
+    let viewport = window().inner_width().ok().and_then(|width| width.as_f64()).unwrap_or_default();
+    let content = document().document_element().map(|el| el.client_width() as f64).unwrap_or(viewport);
+    let scrollbar = (viewport - content).max(0.0);
+
+    let _ = style.set_property("overflow", "hidden");
+    if scrollbar > 0.0 {
+        let _ = style.set_property("padding-right", &format!("{}px", scrollbar));
+    }
+    Some(saved)
+}
+
+fn unlock_scroll((overflow, padding_right): (String, String)) {
+    if let Some(body) = document().body() {
+        let style = body.style();
+        let _ = style.set_property("overflow", &overflow);
+        let _ = style.set_property("padding-right", &padding_right);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DialogSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+    /// Nearly full-viewport, for lightboxes and the chatbot.
+    Full,
+}
+
+impl DialogSize {
+    pub fn class(&self) -> &'static str {
+        match self {
+            DialogSize::Small => "max-w-sm",
+            DialogSize::Medium => "max-w-lg",
+            DialogSize::Large => "max-w-3xl",
+            DialogSize::Full => "max-w-[95vw] h-[90vh]",
+        }
+    }
+}
+
+/// Modal dialog rendered into `<body>` through a portal.
+///
+/// While open, focus is trapped in the panel, page scroll is locked and
+/// Escape or a backdrop click sets `open` to false. Focus starts on the
+/// element matching `initial_focus` (else `[autofocus]`, else the first
+/// focusable element) and returns to whatever had it before on close.
+/// Dialogs opened from inside a dialog stack above it and Escape closes the
+/// innermost first. `alert` renders an `alertdialog` that only closes through
+/// its own buttons.
+#[component]
+pub fn Dialog(
+    open: RwSignal<bool>,
+    #[prop(into)] title: String,
+    #[prop(optional, into)] description: Option<String>,
+    #[prop(optional)] size: DialogSize,
+    #[prop(optional)] alert: bool,
+    #[prop(optional)] initial_focus: Option<&'static str>,
+    #[prop(optional, into)] on_close: Option<Callback<()>>,
+    #[prop(optional, into)] class: Option<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let id = next_id();
+    let title_id = format!("dialog-{}-title", id);
+    let description_id = description.as_ref().map(|_| format!("dialog-{}-description", id));
+    let panel = NodeRef::<html::Div>::new();
+
+    // `mounted` keeps the portal alive through the exit transition; `visible`
+    // drives the transition itself.
+    let mounted = RwSignal::new(false);
+    let visible = RwSignal::new(false);
+    let depth = RwSignal::new(1usize);
+    let restore = StoredValue::new_local(None::<web_sys::HtmlElement>);
+
+    let close = move || {
+        open.set(false);
+        if let Some(on_close) = on_close {
+            on_close.run(());
+        }
+    };
+
+    Effect::new(move |was_open: Option<bool>| {
+        let is_open = open.get();
+        if was_open.unwrap_or(false) == is_open {
+            return is_open;
+        }
+
+        if is_open {
+            restore.set_value(focus::active_element());
+            depth.set(stack_push(id));
+            mounted.set(true);
+            // Two frames: one to mount in the hidden state, one to transition in.
+            request_animation_frame(move || {
+                request_animation_frame(move || {
+                    visible.set(true);
+                    if let Some(panel) = panel.get_untracked() {
+                        focus::focus_initial(&panel, initial_focus);
+                    }
+                });
+            });
+        } else {
+            visible.set(false);
+            stack_remove(id);
+            let finish = move || {
+                // Reopened during the exit transition.
+                if open.try_get_untracked().unwrap_or(false) {
+                    return;
+                }
+                mounted.try_set(false);
+                if let Some(Some(el)) = restore.try_get_value() {
+                    let _ = el.focus();
+                }
+            };
+            if focus::prefers_reduced_motion() {
+                finish();
+            } else {
+                set_timeout(finish, TRANSITION);
+            }
+        }
+        is_open
+    });
+
+    on_cleanup(move || stack_remove(id));
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() == "Escape" && !alert {
+            ev.prevent_default();
+            ev.stop_propagation();
+            close();
+        } else if let Some(panel) = panel.get_untracked() {
+            focus::trap_tab(&ev, &panel);
+        }
+    };
+
+    // The portal content is rebuilt on every open, so owned props live in
+    // stored values the nested closures can copy.
+    let title = StoredValue::new(title);
+    let title_id = StoredValue::new(title_id);
+    let description = StoredValue::new(description);
+    let description_id = StoredValue::new(description_id);
+    let children = StoredValue::new(children);
+    let panel_class = format!(
+        "glass-card relative w-full max-h-[90vh] overflow-y-auto pointer-events-auto bg-surface/80 focus:outline-none transition duration-200 ease-out motion-reduce:transition-none {} {}",
+        size.class(),
+        class.unwrap_or_default()
+    );
+    let panel_class = StoredValue::new(panel_class);
+
+    view! {
+        <Show when=move || mounted.get()>
+            <Portal>
+                <div
+                    class="fixed inset-0 flex items-center justify-center p-4 pointer-events-none"
+                    style=move || format!("z-index: {}", 50 + depth.get() * 10)
+                >
+                    <div
+                        class="absolute inset-0 pointer-events-auto bg-base00/60 backdrop-blur-sm transition-opacity duration-200 motion-reduce:transition-none"
+                        class:opacity-0=move || !visible.get()
+                        aria-hidden="true"
+                        on:click=move |_| {
+                            if !alert {
+                                close();
+                            }
+                        }
+                    ></div>
+                    <div
+                        node_ref=panel
+                        role=if alert { "alertdialog" } else { "dialog" }
+                        aria-modal="true"
+                        aria-labelledby=title_id.get_value()
+                        aria-describedby=description_id.get_value()
+                        tabindex="-1"
+                        class=panel_class.get_value()
+                        class:opacity-0=move || !visible.get()
+                        class:scale-95=move || !visible.get()
+                        on:keydown=on_keydown
+                    >
+                        <div class="flex items-start justify-between gap-4 mb-4">
+                            <div>
+                                <h2 id=title_id.get_value() class="text-xl font-outfit font-semibold text-text-primary">
+                                    {title.get_value()}
+                                </h2>
+                                {description.get_value().map(|description| view! {
+                                    <p id=description_id.get_value() class="mt-1 text-sm font-inter text-text-secondary">
+                                        {description}
+                                    </p>
+                                })}
+                            </div>
+                            {(!alert).then(|| view! {
+                                <button
+                                    type="button"
+                                    class="shrink-0 w-8 h-8 rounded-full flex items-center justify-center text-text-muted hover:text-accent hover:bg-base02 focus:outline-none focus-visible:ring-2 focus-visible:ring-accent"
+                                    aria-label="Close dialog"
+                                    on:click=move |_| close()
+                                >
+                                    <span aria-hidden="true">"✕"</span>
+                                </button>
+                            })}
+                        </div>
+                        {children.with_value(|children| children())}
+                    </div>
+                </div>
+            </Portal>
+        </Show>
+    }
+}
@@ -0,0 +1,261 @@
+//! Collapsible sections: a standalone `Disclosure` and an `Accordion` of
+//! `AccordionItem`s.
+//!
+//! Both share one section layout: a heading wrapping the toggle button
+//! (`aria-expanded`/`aria-controls`) and a region whose height animates
+//! through a `grid-template-rows` transition, so no measuring is needed.
+//! Collapsed content is `inert`, keeping it out of the tab order and the
+//! accessibility tree.
+
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::components::hash;
+use crate::components::ids::unique_id;
+
+/// Open the section with `id` when the URL hash names it, scrolling it into
+/// view, and keep the hash pointing at the most recently opened section.
+fn link_to_hash(id: String, is_open: Signal<bool>, open: impl Fn() + Clone + 'static) {
+    let target = id.clone();
+    hash::watch(move |hash| {
+        if hash == target {
+            open();
+            if let Some(section) = document().get_element_by_id(&target) {
+                section.scroll_into_view();
+            }
+        }
+    });
+
+    Effect::new(move |was_open: Option<bool>| {
+        let is_open = is_open.get();
+        // Only react to changes, not the initial state.
+        if was_open.is_some_and(|was_open| was_open != is_open) {
+            if is_open {
+                hash::replace(Some(&id));
+            } else if hash::current() == id {
+                hash::replace(None);
+            }
+        }
+        is_open
+    });
+}
+
+/// The section markup shared by `Disclosure` and `AccordionItem`.
+#[component]
+fn Section(
+    id: String,
+    title: String,
+    level: u8,
+    is_open: Signal<bool>,
+    on_toggle: Callback<()>,
+    #[prop(optional)] on_keydown: Option<UnsyncCallback<(ev::KeyboardEvent,)>>,
+    class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let button_id = format!("{}-button", id);
+    let panel_id = format!("{}-panel", id);
+
+    view! {
+        <div id=id class=format!("border-b border-border {}", class.unwrap_or_default())>
+            <div role="heading" attr:aria-level=level.to_string()>
+                <button
+                    type="button"
+                    id=button_id.clone()
+                    data-disclosure-trigger=""
+                    aria-expanded=move || is_open.get().to_string()
+                    aria-controls=panel_id.clone()
+                    class="w-full flex items-center justify-between gap-4 py-4 text-left font-outfit font-medium text-text-primary hover:text-accent focus:outline-none focus-visible:ring-2 focus-visible:ring-accent rounded-md transition-colors"
+                    on:click=move |_| on_toggle.run(())
+                    on:keydown=move |ev| {
+                        if let Some(on_keydown) = on_keydown {
+                            on_keydown.run((ev,));
+                        }
+                    }
+                >
+                    <span>{title}</span>
+                    <span
+                        class="shrink-0 text-text-muted transition-transform duration-300 motion-reduce:transition-none"
+                        class:rotate-180=move || is_open.get()
+                        aria-hidden="true"
+                    >
+                        "▾"
+                    </span>
+                </button>
+            </div>
+            <div
+                id=panel_id
+                role="region"
+                aria-labelledby=button_id
+                class="grid transition-[grid-template-rows] duration-300 ease-out motion-reduce:transition-none"
+                class=("grid-rows-[1fr]", move || is_open.get())
+                class=("grid-rows-[0fr]", move || !is_open.get())
+                inert=move || (!is_open.get()).then_some("")
+            >
+                <div class="overflow-hidden">
+                    <div class="pb-4 font-inter text-text-secondary">{children()}</div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// A single collapsible section.
+///
+/// Pass `open` to control it from outside. Giving it an `id` makes it
+/// deep-linkable: `#id` in the URL opens it and scrolls to it, and opening it
+/// puts `#id` in the URL.
+#[component]
+pub fn Disclosure(
+    #[prop(into)] title: String,
+    #[prop(optional)] open: Option<RwSignal<bool>>,
+    #[prop(optional)] default_open: bool,
+    #[prop(optional, into)] id: Option<String>,
+    /// `aria-level` of the heading wrapping the toggle.
+    #[prop(default = 3)]
+    level: u8,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let open = open.unwrap_or_else(|| RwSignal::new(default_open));
+    if let Some(id) = id.clone() {
+        link_to_hash(id, open.into(), move || open.set(true));
+    }
+    let id = id.unwrap_or_else(|| unique_id("disclosure"));
+
+    view! {
+        <Section
+            id=id
+            title=title
+            level=level
+            is_open=open.into()
+            on_toggle=Callback::new(move |_| open.update(|open| *open = !*open))
+            class=class
+        >
+            {children()}
+        </Section>
+    }
+}
+
+#[derive(Clone, Copy)]
+struct AccordionContext {
+    open: RwSignal<Vec<String>>,
+    multiple: bool,
+    deep_link: bool,
+    root: NodeRef<html::Div>,
+}
+
+impl AccordionContext {
+    fn toggle(&self, value: &str) {
+        let multiple = self.multiple;
+        self.open.update(|open| {
+            if let Some(index) = open.iter().position(|open| open == value) {
+                open.remove(index);
+            } else {
+                if !multiple {
+                    open.clear();
+                }
+                open.push(value.to_string());
+            }
+        });
+    }
+
+    fn expand(&self, value: &str) {
+        if !self.open.with_untracked(|open| open.iter().any(|open| open == value)) {
+            self.toggle(value);
+        }
+    }
+}
+
+/// A group of collapsible sections.
+///
+/// Only one item is open at a time unless `multiple` is set. `open` holds the
+/// values of the open items and can be passed in to control them. With
+/// `deep_link`, each item's `value` is also its element id and URL hash,
+/// like a `Disclosure` with an `id`. Up/Down, Home and End move between item
+/// headers.
+#[component]
+pub fn Accordion(
+    #[prop(optional)] multiple: bool,
+    #[prop(optional)] open: Option<RwSignal<Vec<String>>>,
+    #[prop(optional)] default_open: Vec<String>,
+    #[prop(optional)] deep_link: bool,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let root = NodeRef::<html::Div>::new();
+    let open = open.unwrap_or_else(|| RwSignal::new(default_open));
+    provide_context(AccordionContext { open, multiple, deep_link, root });
+
+    view! {
+        <div node_ref=root class=format!("border-t border-border {}", class.unwrap_or_default())>
+            {children()}
+        </div>
+    }
+}
+
+/// A section of an `Accordion`, identified by `value`.
+#[component]
+pub fn AccordionItem(
+    #[prop(into)] value: String,
+    #[prop(into)] title: String,
+    #[prop(default = 3)] level: u8,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<AccordionContext>();
+    let key = StoredValue::new(value.clone());
+    let is_open = Signal::derive(move || key.with_value(|key| context.open.with(|open| open.contains(key))));
+
+    if context.deep_link {
+        link_to_hash(value.clone(), is_open, move || key.with_value(|key| context.expand(key)));
+    }
+    let id = if context.deep_link {
+        value
+    } else {
+        unique_id("accordion")
+    };
+
+    // Arrow keys move between the headers of this accordion only.
+    let on_keydown = UnsyncCallback::new(move |(ev,): (ev::KeyboardEvent,)| {
+        let Some(root) = context.root.get_untracked() else {
+            return;
+        };
+        let Ok(triggers) = root.query_selector_all("[data-disclosure-trigger]") else {
+            return;
+        };
+        let triggers: Vec<web_sys::HtmlElement> = (0..triggers.length())
+            .filter_map(|index| triggers.item(index))
+            .filter_map(|trigger| trigger.dyn_into().ok())
+            .collect();
+        let target = ev.current_target().and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok());
+        let Some(current) = triggers.iter().position(|trigger| Some(trigger) == target.as_ref()) else {
+            return;
+        };
+        let count = triggers.len();
+        let next = match ev.key().as_str() {
+            "ArrowDown" => (current + 1) % count,
+            "ArrowUp" => (current + count - 1) % count,
+            "Home" => 0,
+            "End" => count - 1,
+            _ => return,
+        };
+        ev.prevent_default();
+        let _ = triggers[next].focus();
+    });
+
+    view! {
+        <Section
+            id=id
+            title=title
+            level=level
+            is_open=is_open
+            on_toggle=Callback::new(move |_| key.with_value(|key| context.toggle(key)))
+            on_keydown=on_keydown
+            class=class
+        >
+            {children()}
+        </Section>
+    }
+}
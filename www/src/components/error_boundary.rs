@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use leptos::error::{ErrorBoundary as ViewErrorBoundary, ErrorId, Errors};
+use leptos::prelude::*;
+
+use crate::errors::{self, ErrorReport, ReportKind};
+
+/// A failure shown in place of content, with an optional retry button.
+#[component]
+pub fn ErrorCard(
+    #[prop(into)] title: String,
+    #[prop(optional)] messages: Vec<String>,
+    #[prop(optional, into)] on_retry: Option<Callback<()>>,
+) -> impl IntoView {
+    view! {
+        <div class="glass-card flex flex-col items-center gap-4 py-16 text-center" role="alert">
+            <p class="text-lg font-outfit font-semibold text-error">{title}</p>
+            {messages
+                .into_iter()
+                .map(|message| view! { <p class="text-sm font-mono text-text-muted break-words">{message}</p> })
+                .collect_view()}
+            {on_retry.map(|on_retry| view! {
+                <button type="button" class="btn-secondary" on:click=move |_| on_retry.run(())>"Try again"</button>
+            })}
+        </div>
+    }
+}
+
+/// Catches errors rendered by its children (views returning `Err`) and shows
+/// an `ErrorCard` in their place, so one failing section doesn't take down
+/// the page. Each error is passed to the reporter in `crate::errors`.
+///
+/// "Try again" runs `on_retry`, e.g. to refetch a resource, and renders the
+/// children afresh; so does a change of `reset_key`, such as the current
+/// path, while the error is shown. Panics are not caught here: see
+/// `errors::install_panic_handler`.
+#[component]
+pub fn ErrorBoundary(
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] on_retry: Option<Callback<()>>,
+    #[prop(optional, into)] reset_key: Option<Signal<String>>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let attempt = RwSignal::new(0_usize);
+    let errors_signal = StoredValue::new(None::<ArcRwSignal<Errors>>);
+    // The fallback re-renders whenever the errors change; each is reported once.
+    let reported = StoredValue::new(HashSet::<ErrorId>::new());
+    let retry = move || {
+        if let Some(errors) = errors_signal.get_value() {
+            errors.set(Errors::default());
+        }
+        reported.update_value(HashSet::clear);
+        attempt.update(|attempt| *attempt += 1);
+    };
+
+    if let Some(reset_key) = reset_key {
+        Effect::new(move |previous: Option<String>| {
+            let key = reset_key.get();
+            let failed = errors_signal
+                .with_value(|errors| errors.as_ref().is_some_and(|errors| !errors.with_untracked(Errors::is_empty)));
+            if failed && previous.is_some_and(|previous| previous != key) {
+                retry();
+            }
+            key
+        });
+    }
+
+    let title = title.unwrap_or_else(|| "Something went wrong.".to_string());
+    let fallback = move |errors: ArcRwSignal<Errors>| {
+        errors_signal.set_value(Some(errors.clone()));
+        let messages: Vec<String> = errors.with(|errors| {
+            errors
+                .iter()
+                .map(|(id, error)| {
+                    let message = error.to_string();
+                    if reported.try_update_value(|reported| reported.insert(id.clone())) == Some(true) {
+                        errors::report(&ErrorReport::new(ReportKind::Error, message.clone()));
+                    }
+                    message
+                })
+                .collect()
+        });
+        let on_retry = Callback::new(move |_| {
+            if let Some(on_retry) = on_retry {
+                on_retry.run(());
+            }
+            retry();
+        });
+        view! { <ErrorCard title=title.clone() messages=messages on_retry=on_retry /> }
+    };
+    let children = StoredValue::new(children);
+
+    view! {
+        <ViewErrorBoundary fallback=fallback>
+            {move || {
+                attempt.track();
+                children.with_value(|children| children())
+            }}
+        </ViewErrorBoundary>
+    }
+}
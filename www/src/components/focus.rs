@@ -0,0 +1,79 @@
+//! Focus and motion helpers shared by overlays (dialogs, popovers, menus).
+
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::{document, window};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// Elements that can receive keyboard focus.
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]):not([type=\"hidden\"]), \
+     select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable=\"true\"], [tabindex]:not([tabindex=\"-1\"])";
+
+/// Tabbable descendants of `container`, in DOM order.
+pub fn focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        // Skip hidden elements; they have no layout box.
+        .filter(|el| el.offset_parent().is_some() || el.get_client_rects().length() > 0)
+        .collect()
+}
+
+/// The first tabbable descendant of `container`, including SVG elements
+/// made focusable with `tabindex`.
+pub fn first_focusable(container: &Element) -> Option<Element> {
+    container.query_selector(FOCUSABLE).ok().flatten()
+}
+
+/// Focus the first element matching `selector` inside `container`, falling
+/// back to an `[autofocus]` element, the first tabbable one and finally the
+/// container itself.
+pub fn focus_initial(container: &HtmlElement, selector: Option<&str>) {
+    let target = selector
+        .into_iter()
+        .chain(["[autofocus]"])
+        .find_map(|selector| container.query_selector(selector).ok().flatten())
+        .and_then(|el| el.dyn_into::<HtmlElement>().ok())
+        .or_else(|| focusable_elements(container).into_iter().next());
+
+    let _ = target.unwrap_or_else(|| container.clone()).focus();
+}
+
+/// Keep Tab and Shift+Tab cycling inside `container`.
+pub fn trap_tab(ev: &KeyboardEvent, container: &Element) {
+    if ev.key() != "Tab" {
+        return;
+    }
+    let elements = focusable_elements(container);
+    let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+        ev.prevent_default();
+        return;
+    };
+
+    let active = document().active_element();
+    let outside = active.as_ref().is_none_or(|active| !container.contains(Some(active)));
+    if ev.shift_key() && (outside || active.as_ref() == Some(first.as_ref())) {
+        ev.prevent_default();
+        let _ = last.focus();
+    } else if !ev.shift_key() && (outside || active.as_ref() == Some(last.as_ref())) {
+        ev.prevent_default();
+        let _ = first.focus();
+    }
+}
+
+/// The currently focused element, if it is an `HtmlElement`.
+pub fn active_element() -> Option<HtmlElement> {
+    document().active_element().and_then(|el| el.dyn_into::<HtmlElement>().ok())
+}
+
+/// Whether the user asked the OS for reduced motion.
+pub fn prefers_reduced_motion() -> bool {
+    window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+        .is_some_and(|media| media.matches())
+}
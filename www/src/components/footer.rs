@@ -0,0 +1,238 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::components::icon::{Icon, IconName};
+use crate::components::ids::unique_id;
+use crate::components::validation::validate_email;
+
+/// A single link in a footer column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FooterLink {
+    pub label: &'static str,
+    pub href: &'static str,
+}
+
+impl FooterLink {
+    pub const fn new(label: &'static str, href: &'static str) -> Self {
+        Self { label, href }
+    }
+
+    fn is_external(&self) -> bool {
+        self.href.starts_with("http") || self.href.starts_with("mailto:")
+    }
+}
+
+/// A titled group of secondary navigation links.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FooterColumn {
+    pub title: &'static str,
+    pub links: Vec<FooterLink>,
+}
+
+/// A social connection rendered as an icon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SocialLink {
+    pub label: &'static str,
+    pub href: &'static str,
+    pub icon: IconName,
+}
+
+/// Secondary navigation. Only pages the router serves are listed; add
+/// links here as their routes land.
+pub fn default_footer_columns() -> Vec<FooterColumn> {
+    vec![
+        FooterColumn {
+            title: "Explore",
+            links: vec![
+                FooterLink::new("Home", "/"),
+                FooterLink::new("Interactive Demos", "/showcase"),
+                FooterLink::new("Technology Stack", "/technology"),
+            ],
+        },
+        FooterColumn {
+            title: "Community",
+            links: vec![FooterLink::new("GitHub", "https://github.com/TheCowboyAI")],
+        },
+    ]
+}
+
+pub fn default_social_links() -> Vec<SocialLink> {
+    vec![SocialLink { label: "GitHub", href: "https://github.com/TheCowboyAI", icon: IconName::GitHub }]
+}
+
+/// The current calendar year, read from the browser clock.
+fn current_year() -> u32 {
+    js_sys::Date::new_0().get_full_year()
+}
+
+/// Site footer with link columns, social connections and newsletter signup.
+///
+/// The signup form is only shown with an `on_subscribe` handler to send the
+/// address to; without one it would thank visitors for nothing.
+#[component]
+pub fn Footer(
+    #[prop(optional)] columns: Option<Vec<FooterColumn>>,
+    #[prop(optional)] social_links: Option<Vec<SocialLink>>,
+    #[prop(optional, into)] on_subscribe: Option<Callback<(String,)>>,
+    #[prop(default = true)] show_newsletter: bool,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let columns = columns.unwrap_or_else(default_footer_columns);
+    let social_links = social_links.unwrap_or_else(default_social_links);
+    let on_subscribe = on_subscribe.filter(|_| show_newsletter);
+    let class_value = format!("bg-base01 text-base05 border-t border-border {}", class.unwrap_or_default());
+
+    view! {
+        <footer class=class_value>
+            <div class="container mx-auto px-4 py-12 grid gap-8 grid-cols-1 sm:grid-cols-2 lg:grid-cols-6">
+                {columns
+                    .into_iter()
+                    .map(|column| view! { <FooterLinkColumn column=column /> })
+                    .collect_view()}
+                {on_subscribe.map(|on_subscribe| view! {
+                    <div class="sm:col-span-2">
+                        <NewsletterSignup on_subscribe=on_subscribe />
+                    </div>
+                })}
+            </div>
+            <div class="border-t border-border/50">
+                <div class="container mx-auto px-4 py-6 flex flex-col sm:flex-row gap-4 justify-between items-center">
+                    <p class="text-sm font-inter text-text-muted">
+                        "© " {current_year()} " W3 Memory Bank"
+                    </p>
+                    <ul class="flex items-center gap-4">
+                        {social_links
+                            .into_iter()
+                            .map(|link| view! { <li><SocialIconLink link=link /></li> })
+                            .collect_view()}
+                    </ul>
+                </div>
+            </div>
+        </footer>
+    }
+}
+
+#[component]
+fn FooterLinkColumn(column: FooterColumn) -> impl IntoView {
+    let link_class = "text-sm font-inter text-text-secondary hover:text-accent transition-colors";
+
+    view! {
+        <nav aria-label=column.title>
+            <h2 class="text-sm font-orbitron font-bold tracking-wider uppercase mb-4">{column.title}</h2>
+            <ul class="space-y-2">
+                {column
+                    .links
+                    .into_iter()
+                    .map(|link| {
+                        let anchor = if link.is_external() {
+                            view! {
+                                <a href=link.href class=link_class target="_blank" rel="noopener noreferrer">
+                                    {link.label}
+                                </a>
+                            }
+                            .into_any()
+                        } else {
+                            view! { <A href=link.href attr:class=link_class>{link.label}</A> }.into_any()
+                        };
+                        view! { <li>{anchor}</li> }
+                    })
+                    .collect_view()}
+            </ul>
+        </nav>
+    }
+}
+
+#[component]
+fn SocialIconLink(link: SocialLink) -> impl IntoView {
+    let external = link.href.starts_with("http");
+
+    view! {
+        <a
+            href=link.href
+            target=external.then_some("_blank")
+            rel=external.then_some("noopener noreferrer")
+            aria-label=link.label
+            title=link.label
+            class="block p-2 rounded-md text-text-secondary hover:text-accent hover:bg-base02 transition-colors"
+        >
+            <Icon name=link.icon />
+        </a>
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum SignupStatus {
+    Idle,
+    Invalid(&'static str),
+    Subscribed,
+}
+
+/// Newsletter signup form embedded in the footer.
+#[component]
+pub fn NewsletterSignup(#[prop(into)] on_subscribe: Callback<(String,)>) -> impl IntoView {
+    let email = RwSignal::new(String::new());
+    let status = RwSignal::new(SignupStatus::Idle);
+    let id = unique_id("newsletter");
+    let input_id = format!("{}-email", id);
+    let status_id = format!("{}-status", id);
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let value = email.get_untracked().trim().to_string();
+        match validate_email(&value) {
+            Ok(()) => {
+                on_subscribe.run((value,));
+                email.set(String::new());
+                status.set(SignupStatus::Subscribed);
+            }
+            Err(message) => status.set(SignupStatus::Invalid(message)),
+        }
+    };
+
+    let invalid = move || matches!(status.get(), SignupStatus::Invalid(_));
+    let described_by = {
+        let status_id = status_id.clone();
+        move || (status.get() != SignupStatus::Idle).then(|| status_id.clone())
+    };
+    let message = move || match status.get() {
+        SignupStatus::Idle => None,
+        SignupStatus::Invalid(message) => Some(view! {
+            <p id=status_id.clone() class="mt-2 text-sm text-error" role="alert">{message}</p>
+        }.into_any()),
+        SignupStatus::Subscribed => Some(view! {
+            <p id=status_id.clone() class="mt-2 text-sm text-success" role="status">
+                "Thanks! You're on the list."
+            </p>
+        }.into_any()),
+    };
+
+    view! {
+        <form on:submit=on_submit novalidate=true>
+            <h2 class="text-sm font-orbitron font-bold tracking-wider uppercase mb-4">"Newsletter"</h2>
+            <p class="text-sm font-inter text-text-secondary mb-4">
+                "Updates on the Memory Bank, new demos and research notes."
+            </p>
+            <label for=input_id.clone() class="sr-only">"Email address"</label>
+            <div class="flex gap-2">
+                <input
+                    id=input_id
+                    type="email"
+                    autocomplete="email"
+                    placeholder="you@example.com"
+                    class="input flex-1 min-w-0"
+                    aria-invalid=move || invalid().to_string()
+                    aria-describedby=described_by
+                    prop:value=move || email.get()
+                    on:input=move |ev| {
+                        email.set(event_target_value(&ev));
+                        if invalid() {
+                            status.set(SignupStatus::Idle);
+                        }
+                    }
+                />
+                <button type="submit" class="btn-primary">"Subscribe"</button>
+            </div>
+            {message}
+        </form>
+    }
+}
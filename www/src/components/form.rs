@@ -0,0 +1,629 @@
+//! Typed form state: field registration, validation, touched/dirty tracking
+//! and the submit lifecycle.
+//!
+//! A [`FormState`] owns a set of typed [`Field`]s. Each field keeps its value
+//! in a signal that binds straight to the input components, and derives its
+//! error from its validators, the last async check and the last server
+//! response. Async and server errors remember the value they were reported
+//! for and disappear as soon as the user edits it. None of this needs the DOM:
+//! `FormState::submit_with` can be awaited directly in tests.
+//!
+//! ```ignore
+//! let form = FormState::new();
+//! let email = form
+//!     .field("email", String::new())
+//!     .validator(Validator::required())
+//!     .validator(Validator::email());
+//!
+//! view! {
+//!     <Form form=form on_submit=move || send(email.value().get_untracked())>
+//!         <TextInput label="Email" value=email.value() error=email.error() on_blur=email.on_blur() />
+//!         <Button button_type=ButtonType::Submit loading=Signal::derive(move || form.is_pending())>"Send"</Button>
+//!     </Form>
+//! }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::validation::{AsyncValidator, Validator};
+
+/// Where a form is in its submit lifecycle.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum SubmitStatus {
+    #[default]
+    Idle,
+    /// Running async validators before submitting.
+    Validating,
+    /// Waiting for the submit handler.
+    Pending,
+    Succeeded,
+    /// Client-side validation failed; nothing was sent.
+    Invalid,
+    /// The submit handler returned an error.
+    Failed(String),
+}
+
+/// Error returned by a submit handler. Field errors are shown on the field
+/// with the matching name; anything else becomes the form-level message.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubmitError {
+    pub message: Option<String>,
+    pub fields: Vec<(String, String)>,
+}
+
+impl SubmitError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: Some(message.into()), fields: Vec::new() }
+    }
+
+    /// Attach an error to the field registered as `name`.
+    pub fn field(mut self, name: impl Into<String>, message: impl Into<String>) -> Self {
+        self.fields.push((name.into(), message.into()));
+        self
+    }
+}
+
+/// `message` if it was reported for `value` and the value hasn't changed since.
+fn current_for<T: PartialEq>(reported: &Option<(T, String)>, value: &T) -> Option<String> {
+    reported.as_ref().filter(|(at, _)| at == value).map(|(_, message)| message.clone())
+}
+
+/// A typed, registered form field. `Copy`, so it can be moved into any
+/// number of closures.
+pub struct Field<T: Send + Sync + 'static> {
+    name: &'static str,
+    value: RwSignal<T>,
+    initial: StoredValue<T>,
+    touched: RwSignal<bool>,
+    validating: RwSignal<bool>,
+    /// Bumped by every async validation, so only the latest run reports.
+    async_run: StoredValue<u64>,
+    async_error: RwSignal<Option<(T, String)>>,
+    server_error: RwSignal<Option<(T, String)>>,
+    validators: StoredValue<Vec<Validator<T>>>,
+    async_validators: StoredValue<Vec<AsyncValidator<T>>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for Field<T> {}
+
+impl<T> Field<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn new(name: &'static str, initial: T) -> Self {
+        Self {
+            name,
+            value: RwSignal::new(initial.clone()),
+            initial: StoredValue::new(initial),
+            touched: RwSignal::new(false),
+            validating: RwSignal::new(false),
+            async_run: StoredValue::new(0),
+            async_error: RwSignal::new(None),
+            server_error: RwSignal::new(None),
+            validators: StoredValue::new(Vec::new()),
+            async_validators: StoredValue::new(Vec::new()),
+        }
+    }
+
+    /// Add a synchronous validator. Validators run in order and the first
+    /// failure is reported.
+    pub fn validator(self, validator: Validator<T>) -> Self {
+        self.validators.update_value(|validators| validators.push(validator));
+        self
+    }
+
+    /// Add an async validator, run on blur and before submit.
+    pub fn async_validator(self, validator: AsyncValidator<T>) -> Self {
+        self.async_validators.update_value(|validators| validators.push(validator));
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The value signal, for binding to an input.
+    pub fn value(&self) -> RwSignal<T> {
+        self.value
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.touched.get()
+    }
+
+    /// Whether the value differs from the initial one.
+    pub fn is_dirty(&self) -> bool {
+        self.initial.with_value(|initial| self.value.with(|value| value != initial))
+    }
+
+    pub fn is_validating(&self) -> bool {
+        self.validating.get()
+    }
+
+    /// First failing synchronous validator for the current value.
+    fn sync_error(&self) -> Option<String> {
+        self.value.with(|value| {
+            self.validators
+                .with_value(|validators| validators.iter().find_map(|validator| validator.validate(value).err()))
+        })
+    }
+
+    /// The error for the current value, whether or not it should be shown yet.
+    pub fn current_error(&self) -> Option<String> {
+        self.sync_error().or_else(|| {
+            self.value.with(|value| {
+                self.async_error
+                    .with(|reported| current_for(reported, value))
+                    .or_else(|| self.server_error.with(|reported| current_for(reported, value)))
+            })
+        })
+    }
+
+    /// The error to display: only once the field has been touched, so a
+    /// pristine form isn't covered in red.
+    pub fn error(&self) -> Signal<Option<String>> {
+        let field = *self;
+        Signal::derive(move || if field.touched.get() { field.current_error() } else { None })
+    }
+
+    pub fn touch(&self) {
+        self.touched.set(true);
+    }
+
+    /// Blur handler for inputs: marks the field touched and runs its async
+    /// validators.
+    pub fn on_blur(&self) -> Callback<()> {
+        let field = *self;
+        Callback::new(move |_| {
+            field.touch();
+            if field.async_validators.with_value(|validators| !validators.is_empty()) {
+                spawn_local(async move {
+                    field.validate_async().await;
+                });
+            }
+        })
+    }
+
+    /// Run every validator, async ones last. Returns whether the value is valid.
+    ///
+    /// A run overtaken by a later one still returns its result but doesn't
+    /// touch the field, so a slow check can't overwrite a newer error.
+    pub async fn validate_async(&self) -> bool {
+        let run = self.next_async_run();
+        let validators = self.async_validators.get_value();
+        if self.sync_error().is_some() || validators.is_empty() {
+            // Nothing to await; this run still supersedes any in flight.
+            self.async_error.set(None);
+            self.validating.set(false);
+            return self.sync_error().is_none();
+        }
+
+        let value = self.value.get_untracked();
+        self.validating.set(true);
+        let mut error = None;
+        for validator in validators {
+            if let Err(message) = validator.validate(value.clone()).await {
+                error = Some(message);
+                break;
+            }
+        }
+        let valid = error.is_none();
+        if self.async_run.try_get_value() == Some(run) {
+            self.async_error.try_set(error.map(|message| (value, message)));
+            self.validating.try_set(false);
+        }
+        valid
+    }
+
+    /// Start a new async validation run, superseding any in flight.
+    fn next_async_run(&self) -> u64 {
+        self.async_run.update_value(|run| *run += 1);
+        self.async_run.get_value()
+    }
+
+    /// Show an error from the server until the value changes.
+    pub fn set_server_error(&self, message: impl Into<String>) {
+        self.server_error.set(Some((self.value.get_untracked(), message.into())));
+    }
+
+    pub fn reset(&self) {
+        self.next_async_run();
+        self.value.set(self.initial.get_value());
+        self.touched.set(false);
+        self.validating.set(false);
+        self.async_error.set(None);
+        self.server_error.set(None);
+    }
+}
+
+/// Type-erased view of a field, so one form can hold fields of any type.
+trait AnyField: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn touch(&self);
+    fn is_valid(&self) -> bool;
+    fn is_dirty(&self) -> bool;
+    fn validate_async(&self) -> Pin<Box<dyn Future<Output = bool>>>;
+    fn set_server_error(&self, message: String);
+    fn clear_server_error(&self);
+    fn reset(&self);
+}
+
+impl<T> AnyField for Field<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn touch(&self) {
+        Field::touch(self);
+    }
+
+    fn is_valid(&self) -> bool {
+        self.current_error().is_none()
+    }
+
+    fn is_dirty(&self) -> bool {
+        Field::is_dirty(self)
+    }
+
+    fn validate_async(&self) -> Pin<Box<dyn Future<Output = bool>>> {
+        let field = *self;
+        Box::pin(async move { field.validate_async().await })
+    }
+
+    fn set_server_error(&self, message: String) {
+        Field::set_server_error(self, message);
+    }
+
+    fn clear_server_error(&self) {
+        self.server_error.set(None);
+    }
+
+    fn reset(&self) {
+        Field::reset(self);
+    }
+}
+
+/// State of one form: its registered fields and submit status.
+#[derive(Clone, Copy)]
+pub struct FormState {
+    fields: StoredValue<Vec<Arc<dyn AnyField>>>,
+    status: RwSignal<SubmitStatus>,
+}
+
+impl FormState {
+    pub fn new() -> Self {
+        Self { fields: StoredValue::new(Vec::new()), status: RwSignal::new(SubmitStatus::Idle) }
+    }
+
+    /// Register a field called `name`. The name is what server errors refer to.
+    pub fn field<T>(&self, name: &'static str, initial: T) -> Field<T>
+    where
+        T: Clone + PartialEq + Send + Sync + 'static,
+    {
+        let field = Field::new(name, initial);
+        self.fields.update_value(|fields| fields.push(Arc::new(field)));
+        field
+    }
+
+    pub fn status(&self) -> SubmitStatus {
+        self.status.get()
+    }
+
+    /// True while validating or waiting for the submit handler.
+    pub fn is_pending(&self) -> bool {
+        matches!(self.status.get(), SubmitStatus::Validating | SubmitStatus::Pending)
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.fields.with_value(|fields| fields.iter().any(|field| field.is_dirty()))
+    }
+
+    /// Whether every field currently passes its validators.
+    pub fn is_valid(&self) -> bool {
+        self.fields.with_value(|fields| fields.iter().all(|field| field.is_valid()))
+    }
+
+    /// Form-level error from the last failed submit.
+    pub fn error(&self) -> Option<String> {
+        match self.status.get() {
+            SubmitStatus::Failed(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn reset(&self) {
+        self.fields.with_value(|fields| fields.iter().for_each(|field| field.reset()));
+        self.status.set(SubmitStatus::Idle);
+    }
+
+    /// Validate every field and, if all pass, run `handler`. Field errors
+    /// it returns are mapped onto the fields by name. Returns whether the
+    /// submit succeeded; ignored while a submit is already in flight.
+    pub async fn submit_with<F, Fut>(&self, handler: F) -> bool
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(), SubmitError>>,
+    {
+        if self.is_pending_untracked() {
+            return false;
+        }
+        let fields = self.fields.get_value();
+        for field in &fields {
+            field.clear_server_error();
+            field.touch();
+        }
+        if !fields.iter().all(|field| field.is_valid()) {
+            self.status.set(SubmitStatus::Invalid);
+            return false;
+        }
+
+        self.status.set(SubmitStatus::Validating);
+        let mut valid = true;
+        // Check every field, not just up to the first failure, so all errors show.
+        for field in &fields {
+            valid &= field.validate_async().await;
+        }
+        if !valid {
+            self.status.try_set(SubmitStatus::Invalid);
+            return false;
+        }
+
+        self.status.try_set(SubmitStatus::Pending);
+        match handler().await {
+            Ok(()) => {
+                self.status.try_set(SubmitStatus::Succeeded);
+                true
+            }
+            Err(SubmitError { message, fields: field_errors }) => {
+                let mut unmatched = Vec::new();
+                for (name, error) in field_errors {
+                    match fields.iter().find(|field| field.name() == name) {
+                        Some(field) => field.set_server_error(error),
+                        None => unmatched.push(error),
+                    }
+                }
+                let message = message
+                    .into_iter()
+                    .chain(unmatched)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let message = if message.is_empty() { "Please fix the highlighted fields.".to_string() } else { message };
+                self.status.try_set(SubmitStatus::Failed(message));
+                false
+            }
+        }
+    }
+
+    /// [`submit_with`](Self::submit_with) on the local executor.
+    pub fn submit<F, Fut>(&self, handler: F)
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = Result<(), SubmitError>> + 'static,
+    {
+        let form = *self;
+        spawn_local(async move {
+            form.submit_with(handler).await;
+        });
+    }
+
+    fn is_pending_untracked(&self) -> bool {
+        matches!(self.status.get_untracked(), SubmitStatus::Validating | SubmitStatus::Pending)
+    }
+}
+
+impl Default for FormState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `<form>` bound to a [`FormState`]: submitting runs validation and then
+/// `on_submit`, and a failed submit shows its message above the children.
+#[component]
+pub fn Form<F, Fut>(
+    form: FormState,
+    on_submit: F,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView
+where
+    F: Fn() -> Fut + Clone + 'static,
+    Fut: Future<Output = Result<(), SubmitError>> + 'static,
+{
+    view! {
+        <form
+            novalidate=true
+            class=class.unwrap_or_default()
+            aria-busy=move || form.is_pending().then_some("true")
+            on:submit=move |ev| {
+                ev.prevent_default();
+                form.submit(on_submit.clone());
+            }
+        >
+            {move || form.error().map(|message| view! {
+                <p class="mb-4 p-3 rounded border border-error/50 bg-error/10 text-sm font-inter text-error" role="alert">
+                    {message}
+                </p>
+            })}
+            {children()}
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::task::Poll;
+
+    use futures::channel::oneshot;
+    use futures::executor::block_on;
+    use futures::future::join;
+
+    use super::*;
+
+    /// Let the other joined futures run before continuing.
+    async fn yield_now() {
+        let mut yielded = false;
+        futures::future::poll_fn(|cx| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    fn always(result: Result<(), &'static str>) -> AsyncValidator<String> {
+        AsyncValidator::new(move |_| async move { result.map_err(str::to_string) })
+    }
+
+    #[test]
+    fn errors_show_once_touched() {
+        let form = FormState::new();
+        let name = form.field("name", String::new()).validator(Validator::required());
+        assert_eq!(name.error().get(), None);
+        assert!(name.current_error().is_some());
+        name.touch();
+        assert_eq!(name.error().get(), Some("This field is required.".to_string()));
+        name.value().set("Ada".to_string());
+        assert_eq!(name.error().get(), None);
+        assert!(name.is_dirty());
+    }
+
+    #[test]
+    fn invalid_submit_touches_every_field_and_skips_the_handler() {
+        let form = FormState::new();
+        let name = form.field("name", String::new()).validator(Validator::required());
+        let email = form.field("email", String::new()).validator(Validator::email());
+        let called = Arc::new(Mutex::new(false));
+        let submitted = block_on(form.submit_with({
+            let called = called.clone();
+            move || async move {
+                *called.lock().unwrap() = true;
+                Ok(())
+            }
+        }));
+        assert!(!submitted);
+        assert!(!*called.lock().unwrap());
+        assert_eq!(form.status(), SubmitStatus::Invalid);
+        assert!(name.is_touched() && email.is_touched());
+    }
+
+    #[test]
+    fn server_errors_map_to_fields_until_edited() {
+        let form = FormState::new();
+        let handle = form.field("handle", "ada".to_string());
+        let submitted = block_on(form.submit_with(|| async {
+            Err(SubmitError::new("Not saved.").field("handle", "Taken.").field("other", "Unknown field."))
+        }));
+        assert!(!submitted);
+        assert_eq!(form.error(), Some("Not saved. Unknown field.".to_string()));
+        assert_eq!(handle.error().get(), Some("Taken.".to_string()));
+        handle.value().set("ada2".to_string());
+        assert_eq!(handle.error().get(), None);
+    }
+
+    #[test]
+    fn async_validators_run_after_sync_ones_pass() {
+        let form = FormState::new();
+        let runs = Arc::new(Mutex::new(0));
+        let handle = form.field("handle", String::new()).validator(Validator::required()).async_validator({
+            let runs = runs.clone();
+            AsyncValidator::new(move |_| {
+                *runs.lock().unwrap() += 1;
+                async { Err("Taken.".to_string()) }
+            })
+        });
+        assert!(!block_on(handle.validate_async()));
+        assert_eq!(*runs.lock().unwrap(), 0);
+
+        handle.value().set("ada".to_string());
+        assert!(!block_on(form.submit_with(|| async { Ok(()) })));
+        assert_eq!(*runs.lock().unwrap(), 1);
+        assert_eq!(handle.error().get(), Some("Taken.".to_string()));
+
+        let form = FormState::new();
+        form.field("handle", "ada".to_string()).async_validator(always(Ok(())));
+        assert!(block_on(form.submit_with(|| async { Ok(()) })));
+        assert_eq!(form.status(), SubmitStatus::Succeeded);
+    }
+
+    #[test]
+    fn stale_async_results_are_dropped() {
+        let form = FormState::new();
+        let pending = Arc::new(Mutex::new(Vec::new()));
+        let handle = form.field("handle", "slow".to_string()).async_validator({
+            let pending = pending.clone();
+            AsyncValidator::new(move |_| {
+                let (send, receive) = oneshot::channel::<Result<(), String>>();
+                pending.lock().unwrap().push(send);
+                async move { receive.await.unwrap_or(Ok(())) }
+            })
+        });
+
+        let first = handle.validate_async();
+        handle.value().set("fast".to_string());
+        let second = handle.validate_async();
+        // Both checks are waiting by the time this is first polled. The
+        // newer one answers first; the older one only after it has finished.
+        let finish = async {
+            let mut senders = pending.lock().unwrap().drain(..).collect::<Vec<_>>().into_iter();
+            let (slow, fast) = (senders.next().unwrap(), senders.next().unwrap());
+            fast.send(Err("Taken.".to_string())).unwrap();
+            yield_now().await;
+            slow.send(Ok(())).unwrap();
+        };
+        let ((first, second), ()) = block_on(join(join(first, second), finish));
+
+        assert!(first && !second);
+        assert!(!handle.is_validating());
+        assert_eq!(handle.current_error(), Some("Taken.".to_string()));
+    }
+
+    #[test]
+    fn sync_errors_end_a_pending_async_run() {
+        let form = FormState::new();
+        let pending = Arc::new(Mutex::new(None));
+        let handle = form.field("handle", "ada".to_string()).validator(Validator::required()).async_validator({
+            let pending = pending.clone();
+            AsyncValidator::new(move |_| {
+                let (send, receive) = oneshot::channel::<Result<(), String>>();
+                *pending.lock().unwrap() = Some(send);
+                async move { receive.await.unwrap_or(Ok(())) }
+            })
+        });
+
+        let slow = handle.validate_async();
+        let finish = async {
+            yield_now().await;
+            assert!(handle.is_validating());
+            handle.value().set(String::new());
+            assert!(!handle.validate_async().await);
+            assert!(!handle.is_validating());
+            let send = pending.lock().unwrap().take().unwrap();
+            send.send(Err("Taken.".to_string())).unwrap();
+        };
+        let (slow, ()) = block_on(join(slow, finish));
+
+        assert!(!slow);
+        assert!(!handle.is_validating());
+        assert_eq!(handle.current_error(), Some("This field is required.".to_string()));
+    }
+}
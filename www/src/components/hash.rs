@@ -0,0 +1,41 @@
+//! URL-hash helpers for deep-linkable widgets (tabs, disclosures).
+//!
+//! The hash is updated with `history.replaceState`, so switching sections
+//! doesn't add history entries or make the browser jump to an anchor.
+
+use leptos::ev;
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// The location hash without its `#`.
+pub fn current() -> String {
+    window().location().hash().unwrap_or_default().trim_start_matches('#').to_string()
+}
+
+/// Set the hash to `#value`, or clear it with `None`.
+pub fn replace(value: Option<&str>) {
+    let url = match value {
+        Some(value) => format!("#{}", value),
+        // An empty hash still leaves a trailing `#`; rebuild the URL without it.
+        None => {
+            let location = window().location();
+            format!(
+                "{}{}",
+                location.pathname().unwrap_or_default(),
+                location.search().unwrap_or_default()
+            )
+        }
+    };
+    if let Ok(history) = window().history() {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+    }
+}
+
+/// Call `f` with the current hash once the calling component has rendered,
+/// and again on every `hashchange` until the owner is cleaned up.
+pub fn watch(f: impl Fn(String) + Clone + 'static) {
+    let initial = f.clone();
+    request_animation_frame(move || initial(current()));
+    let listener = window_event_listener(ev::hashchange, move |_| f(current()));
+    on_cleanup(move || listener.remove());
+}
@@ -0,0 +1,859 @@
+//! Small tokenizer-based syntax highlighter for the languages that appear in
+//! our docs: Rust, TOML, Nix, JSON, shell, Cypher and Mermaid.
+//!
+//! It is deliberately lexical — no parsing, no grammar files — and runs in
+//! plain Rust so it costs nothing beyond the wasm bundle. Tokens map to
+//! Base16 roles through [`TokenKind::class`], so highlighting follows the
+//! active theme.
+
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Toml,
+    Nix,
+    Json,
+    Shell,
+    Cypher,
+    Mermaid,
+    #[default]
+    Plain,
+}
+
+impl Language {
+    /// Resolve a fence/`language` name, accepting common aliases.
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Language::Rust,
+            "toml" => Language::Toml,
+            "nix" => Language::Nix,
+            "json" | "jsonc" => Language::Json,
+            "sh" | "bash" | "shell" | "zsh" | "console" => Language::Shell,
+            "cypher" | "cql" => Language::Cypher,
+            "mermaid" | "mmd" => Language::Mermaid,
+            _ => Language::Plain,
+        }
+    }
+
+    /// Short label shown in a code block header.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::Toml => "toml",
+            Language::Nix => "nix",
+            Language::Json => "json",
+            Language::Shell => "shell",
+            Language::Cypher => "cypher",
+            Language::Mermaid => "mermaid",
+            Language::Plain => "text",
+        }
+    }
+
+    fn syntax(&self) -> &'static Syntax {
+        match self {
+            Language::Rust => &RUST,
+            Language::Toml => &TOML,
+            Language::Nix => &NIX,
+            Language::Json => &JSON,
+            Language::Shell => &SHELL,
+            Language::Cypher => &CYPHER,
+            Language::Mermaid => &MERMAID,
+            Language::Plain => &PLAIN,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Plain,
+    Comment,
+    Keyword,
+    String,
+    Number,
+    Constant,
+    Function,
+    Type,
+    Variable,
+    Property,
+    Attribute,
+    Macro,
+    Operator,
+    Punctuation,
+}
+
+impl TokenKind {
+    /// Base16 styling guidelines: comments base03, variables base08,
+    /// constants/numbers base09, types base0A, strings base0B, support base0C,
+    /// functions base0D, keywords base0E.
+    pub fn class(&self) -> &'static str {
+        match self {
+            TokenKind::Plain => "",
+            TokenKind::Comment => "text-base03 italic",
+            TokenKind::Keyword => "text-base0E",
+            TokenKind::String => "text-base0B",
+            TokenKind::Number | TokenKind::Constant => "text-base09",
+            TokenKind::Function => "text-base0D",
+            TokenKind::Type => "text-base0A",
+            TokenKind::Variable => "text-base08",
+            TokenKind::Property => "text-base0D",
+            TokenKind::Attribute => "text-base0A",
+            TokenKind::Macro => "text-base0C",
+            TokenKind::Operator => "text-base0C",
+            TokenKind::Punctuation => "text-base04",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+/// Lexical rules for one language.
+struct Syntax {
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    constants: &'static [&'static str],
+    /// Characters besides alphanumerics and `_` allowed inside identifiers.
+    ident_extra: &'static [char],
+    operators: &'static str,
+    keywords_ignore_case: bool,
+}
+
+impl Syntax {
+    fn is_ident_char(&self, c: char) -> bool {
+        c.is_alphanumeric() || c == '_' || self.ident_extra.contains(&c)
+    }
+}
+
+const PLAIN: Syntax = Syntax {
+    line_comments: &[],
+    block_comment: None,
+    quotes: &[],
+    keywords: &[],
+    types: &[],
+    constants: &[],
+    ident_extra: &[],
+    operators: "",
+    keywords_ignore_case: false,
+};
+
+const RUST: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "fn",
+        "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+        "static", "struct", "super", "trait", "type", "unsafe", "use", "where", "while",
+    ],
+    types: &[
+        "Self", "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+        "i128", "isize", "f32", "f64",
+    ],
+    constants: &["true", "false", "None", "Some", "Ok", "Err"],
+    ident_extra: &[],
+    operators: "+-*/%=<>!&|^~?:@",
+    keywords_ignore_case: false,
+};
+
+const TOML: Syntax = Syntax {
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    keywords: &[],
+    types: &[],
+    constants: &["true", "false", "inf", "nan"],
+    ident_extra: &['-'],
+    operators: "=",
+    keywords_ignore_case: false,
+};
+
+const NIX: Syntax = Syntax {
+    line_comments: &["#"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    keywords: &["assert", "else", "if", "in", "inherit", "let", "or", "rec", "then", "with"],
+    types: &[],
+    constants: &["true", "false", "null"],
+    ident_extra: &['-', '\''],
+    operators: "+-*/=<>!&|?:@",
+    keywords_ignore_case: false,
+};
+
+const JSON: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    keywords: &[],
+    types: &[],
+    constants: &["true", "false", "null"],
+    ident_extra: &[],
+    operators: ":",
+    keywords_ignore_case: false,
+};
+
+const SHELL: Syntax = Syntax {
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    keywords: &[
+        "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac", "in",
+        "function", "return", "export", "local", "readonly", "set", "unset",
+    ],
+    types: &[],
+    constants: &["true", "false"],
+    ident_extra: &['-', '.', '/'],
+    operators: "|&;<>=!",
+    keywords_ignore_case: false,
+};
+
+const CYPHER: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\''],
+    keywords: &[
+        "match", "optional", "where", "return", "create", "merge", "delete", "detach", "set", "remove", "with",
+        "unwind", "order", "by", "skip", "limit", "as", "and", "or", "not", "xor", "in", "is", "distinct",
+        "on", "call", "yield", "union", "all", "case", "when", "then", "else", "end", "asc", "desc",
+        "contains", "starts", "ends", "exists",
+    ],
+    types: &[],
+    constants: &["true", "false", "null"],
+    ident_extra: &[],
+    operators: "+-*/%=<>!|^",
+    keywords_ignore_case: true,
+};
+
+const MERMAID: Syntax = Syntax {
+    line_comments: &["%%"],
+    block_comment: None,
+    quotes: &['"'],
+    keywords: &[
+        "graph", "flowchart", "sequenceDiagram", "classDiagram", "stateDiagram",
+        "erDiagram", "gantt", "pie", "journey", "mindmap", "timeline", "subgraph", "end", "participant",
+        "actor", "note", "Note", "loop", "alt", "else", "opt", "par", "and", "rect", "activate", "deactivate",
+        "class", "classDef", "style", "linkStyle", "click", "direction", "title", "section", "TD", "TB",
+        "BT", "LR", "RL",
+    ],
+    types: &[],
+    constants: &[],
+    ident_extra: &[],
+    operators: "-=.<>&",
+    keywords_ignore_case: false,
+};
+
+/// Split `source` into tokens. Concatenating every token's text yields the
+/// input unchanged.
+pub fn tokenize(language: Language, source: &str) -> Vec<Token<'_>> {
+    Lexer { syntax: language.syntax(), language, src: source, pos: 0, tokens: Vec::new() }.run()
+}
+
+/// Tokens grouped per line, splitting tokens that span line breaks (block
+/// comments, multi-line strings). Newlines are not included.
+pub fn highlight_lines(language: Language, source: &str) -> Vec<Vec<Token<'_>>> {
+    let mut lines = vec![Vec::new()];
+    for token in tokenize(language, source) {
+        let mut parts = token.text.split('\n');
+        if let Some(first) = parts.next() {
+            push_part(lines.last_mut().unwrap(), token.kind, first);
+        }
+        for part in parts {
+            lines.push(Vec::new());
+            push_part(lines.last_mut().unwrap(), token.kind, part);
+        }
+    }
+    // A trailing newline doesn't start a visible line.
+    if source.ends_with('\n') && lines.len() > 1 {
+        lines.pop();
+    }
+    lines
+}
+
+fn push_part<'a>(line: &mut Vec<Token<'a>>, kind: TokenKind, text: &'a str) {
+    let text = text.strip_suffix('\r').unwrap_or(text);
+    if !text.is_empty() {
+        line.push(Token { kind, text });
+    }
+}
+
+struct Lexer<'a> {
+    syntax: &'static Syntax,
+    language: Language,
+    src: &'a str,
+    pos: usize,
+    tokens: Vec<Token<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    fn run(mut self) -> Vec<Token<'a>> {
+        while self.pos < self.src.len() {
+            let start = self.pos;
+            let kind = self.next_kind();
+            debug_assert!(self.pos > start, "lexer made no progress");
+            self.push(kind, start);
+        }
+        self.tokens
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat_while(&mut self, pred: impl Fn(char) -> bool) {
+        while self.peek().is_some_and(&pred) {
+            self.bump();
+        }
+    }
+
+    fn eat_until(&mut self, end: &str) {
+        match self.rest().find(end) {
+            Some(offset) => self.pos += offset + end.len(),
+            None => self.pos = self.src.len(),
+        }
+    }
+
+    /// The last token that isn't whitespace.
+    fn prev_significant(&self) -> Option<&Token<'a>> {
+        self.tokens.iter().rev().find(|token| !token.text.trim().is_empty())
+    }
+
+    /// True when only whitespace precedes the current position on its line.
+    fn at_line_start(&self) -> bool {
+        self.src[..self.pos].rsplit('\n').next().is_some_and(|line| line.trim().is_empty())
+    }
+
+    /// The next non-whitespace character after the current position,
+    /// looking past spaces and tabs only.
+    fn next_on_line(&self) -> Option<char> {
+        self.rest().chars().find(|c| *c != ' ' && *c != '\t')
+    }
+
+    fn push(&mut self, kind: TokenKind, start: usize) {
+        let text = &self.src[start..self.pos];
+        match self.tokens.last_mut() {
+            Some(last) if last.kind == kind && matches!(kind, TokenKind::Plain | TokenKind::Punctuation) => {
+                let last_start = last.text.as_ptr() as usize - self.src.as_ptr() as usize;
+                last.text = &self.src[last_start..self.pos];
+            }
+            _ => self.tokens.push(Token { kind, text }),
+        }
+    }
+
+    fn next_kind(&mut self) -> TokenKind {
+        let rest = self.rest();
+        let c = self.peek().unwrap();
+
+        if c.is_whitespace() {
+            self.eat_while(char::is_whitespace);
+            return TokenKind::Plain;
+        }
+
+        if let Some(kind) = self.language_specific(c) {
+            return kind;
+        }
+
+        if let Some(marker) = self.syntax.line_comments.iter().find(|marker| rest.starts_with(**marker)) {
+            // In shell `#` only starts a comment at the beginning of a word.
+            let word_start = self.src[..self.pos].chars().last().is_none_or(char::is_whitespace);
+            if self.language != Language::Shell || word_start {
+                self.pos += marker.len();
+                self.eat_while(|c| c != '\n');
+                return TokenKind::Comment;
+            }
+        }
+
+        if let Some((open, close)) = self.syntax.block_comment {
+            if rest.starts_with(open) {
+                self.pos += open.len();
+                self.eat_until(close);
+                return TokenKind::Comment;
+            }
+        }
+
+        if self.syntax.quotes.contains(&c) {
+            return self.string(c);
+        }
+
+        if c.is_ascii_digit() {
+            return self.number();
+        }
+
+        if is_ident_start(c) {
+            return self.ident();
+        }
+
+        let operators = self.syntax.operators;
+        if operators.contains(c) {
+            self.eat_while(|c| operators.contains(c));
+            return TokenKind::Operator;
+        }
+
+        self.bump();
+        if "()[]{},;.".contains(c) {
+            TokenKind::Punctuation
+        } else {
+            TokenKind::Plain
+        }
+    }
+
+    /// Rules that don't fit the shared table.
+    fn language_specific(&mut self, c: char) -> Option<TokenKind> {
+        let rest = self.rest();
+        match self.language {
+            Language::Rust => {
+                if rest.starts_with("/*") {
+                    self.nested_comment();
+                    return Some(TokenKind::Comment);
+                }
+                if rest.starts_with("#[") || rest.starts_with("#![") {
+                    self.eat_until("]");
+                    return Some(TokenKind::Attribute);
+                }
+                if c == 'r' && (rest.starts_with("r\"") || rest.starts_with("r#\"")) {
+                    let hashes = rest[1..].chars().take_while(|c| *c == '#').count();
+                    let close = format!("\"{}", "#".repeat(hashes));
+                    self.pos += 2 + hashes;
+                    self.eat_until(&close);
+                    return Some(TokenKind::String);
+                }
+                if c == 'b' && (rest.starts_with("b\"") || rest.starts_with("b'")) {
+                    self.bump();
+                    let quote = self.peek().unwrap();
+                    return Some(self.string(quote));
+                }
+                if c == '\'' {
+                    // `'a'` / `'\n'` are chars, `'a` is a lifetime.
+                    let is_char = self.peek_nth(1) == Some('\\') || self.peek_nth(2) == Some('\'');
+                    if is_char {
+                        return Some(self.string('\''));
+                    }
+                    self.bump();
+                    self.eat_while(|c| c.is_alphanumeric() || c == '_');
+                    return Some(TokenKind::Type);
+                }
+                None
+            }
+            Language::Nix => {
+                if rest.starts_with("''") {
+                    self.pos += 2;
+                    self.eat_until("''");
+                    return Some(TokenKind::String);
+                }
+                if rest.starts_with("./") || rest.starts_with("../") || rest.starts_with("~/") || (c == '<' && rest[1..].starts_with(|c: char| c.is_alphabetic())) {
+                    self.eat_while(|c| !c.is_whitespace() && !";)]}".contains(c));
+                    return Some(TokenKind::String);
+                }
+                None
+            }
+            Language::Toml => {
+                if c == '[' && self.at_line_start() {
+                    self.eat_while(|c| c != '\n' && c != '#');
+                    return Some(TokenKind::Type);
+                }
+                for triple in ["\"\"\"", "'''"] {
+                    if rest.starts_with(triple) {
+                        self.pos += 3;
+                        self.eat_until(triple);
+                        return Some(TokenKind::String);
+                    }
+                }
+                None
+            }
+            Language::Shell => {
+                // A `$ ` prompt in console transcripts.
+                if c == '$' && self.peek_nth(1) == Some(' ') && self.at_line_start() {
+                    self.bump();
+                    return Some(TokenKind::Punctuation);
+                }
+                if c == '$' {
+                    self.bump();
+                    match self.peek() {
+                        Some('{') => self.eat_until("}"),
+                        Some('(') => {
+                            self.bump();
+                        }
+                        _ => self.eat_while(|c| c.is_alphanumeric() || c == '_' || "@#?*!$".contains(c)),
+                    }
+                    return Some(TokenKind::Variable);
+                }
+                if c == '-' && self.src[..self.pos].chars().last().is_some_and(char::is_whitespace) {
+                    self.eat_while(|c| c.is_alphanumeric() || c == '-' || c == '_');
+                    return Some(TokenKind::Attribute);
+                }
+                None
+            }
+            Language::Cypher => {
+                if c == '$' {
+                    self.bump();
+                    self.eat_while(|c| c.is_alphanumeric() || c == '_');
+                    return Some(TokenKind::Variable);
+                }
+                if c == ':' && self.peek_nth(1).is_some_and(|c| is_ident_start(c) || c == '`') {
+                    self.bump();
+                    self.eat_while(|c| c.is_alphanumeric() || c == '_' || c == '`');
+                    return Some(TokenKind::Type);
+                }
+                if c == '`' {
+                    return Some(self.string('`'));
+                }
+                None
+            }
+            Language::Json | Language::Mermaid | Language::Plain => None,
+        }
+    }
+
+    /// A Rust block comment, which can contain other block comments.
+    fn nested_comment(&mut self) {
+        let mut depth = 0;
+        while self.pos < self.src.len() {
+            let rest = self.rest();
+            if rest.starts_with("/*") {
+                depth += 1;
+                self.pos += 2;
+            } else if rest.starts_with("*/") {
+                depth -= 1;
+                self.pos += 2;
+                if depth == 0 {
+                    return;
+                }
+            } else {
+                self.bump();
+            }
+        }
+    }
+
+    fn string(&mut self, quote: char) -> TokenKind {
+        self.bump();
+        // TOML literal strings and shell single quotes have no escapes.
+        let escapes = !(quote == '\'' && matches!(self.language, Language::Toml | Language::Shell));
+        while let Some(c) = self.bump() {
+            if c == '\\' && escapes {
+                self.bump();
+            } else if c == quote {
+                break;
+            }
+        }
+
+        // A JSON string followed by `:` is an object key.
+        if self.language == Language::Json && self.next_on_line() == Some(':') {
+            TokenKind::Property
+        } else {
+            TokenKind::String
+        }
+    }
+
+    fn number(&mut self) -> TokenKind {
+        while let Some(c) = self.peek() {
+            let is_fraction = c == '.' && self.peek_nth(1).is_some_and(|c| c.is_ascii_digit());
+            // Dates and times in TOML (`1979-05-27T07:32:00Z`).
+            let is_date = self.language == Language::Toml && matches!(c, '-' | ':');
+            if c.is_ascii_alphanumeric() || c == '_' || is_fraction || is_date {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        TokenKind::Number
+    }
+
+    fn ident(&mut self) -> TokenKind {
+        let start = self.pos;
+        let syntax = self.syntax;
+        self.eat_while(|c| syntax.is_ident_char(c));
+        let word = &self.src[start..self.pos];
+
+        let is_keyword = if syntax.keywords_ignore_case {
+            syntax.keywords.iter().any(|k| k.eq_ignore_ascii_case(word))
+        } else {
+            syntax.keywords.contains(&word)
+        };
+
+        if syntax.constants.contains(&word) {
+            return TokenKind::Constant;
+        }
+        if is_keyword {
+            return TokenKind::Keyword;
+        }
+        if syntax.types.contains(&word) {
+            return TokenKind::Type;
+        }
+
+        let after_dot = self.prev_significant().is_some_and(|token| token.text.ends_with('.'));
+        let next = self.next_on_line();
+        match self.language {
+            Language::Rust => {
+                if self.peek() == Some('!') && self.peek_nth(1) != Some('=') {
+                    self.bump();
+                    TokenKind::Macro
+                } else if next == Some('(') {
+                    TokenKind::Function
+                } else if word.starts_with(char::is_uppercase) {
+                    if word.chars().all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_') && word.len() > 1 {
+                        TokenKind::Constant
+                    } else {
+                        TokenKind::Type
+                    }
+                } else if after_dot {
+                    TokenKind::Property
+                } else {
+                    TokenKind::Plain
+                }
+            }
+            Language::Toml | Language::Nix if next == Some('=') && self.rest().trim_start().chars().nth(1) != Some('=') => {
+                TokenKind::Property
+            }
+            Language::Nix if after_dot => TokenKind::Property,
+            Language::Shell if self.at_command_position(start) => TokenKind::Function,
+            Language::Cypher if next == Some('(') => TokenKind::Function,
+            Language::Cypher if after_dot => TokenKind::Property,
+            _ => TokenKind::Plain,
+        }
+    }
+
+    /// Whether the word starting at `start` is the command of a shell
+    /// pipeline: first on its line or after `|`, `&&`, `;` and friends.
+    fn at_command_position(&self, start: usize) -> bool {
+        let before = self.src[..start].trim_end_matches([' ', '\t']);
+        let line = before.rsplit('\n').next().unwrap_or_default().trim();
+        line.is_empty()
+            || line == "$"
+            || before.ends_with(['|', ';', '&', '(', '`'])
+            || matches!(self.prev_significant(), Some(token) if token.kind == TokenKind::Keyword && matches!(token.text, "then" | "do" | "else"))
+    }
+}
+
+/// Set of 1-based line numbers, written like `"1,3-5"`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineRanges(Vec<RangeInclusive<usize>>);
+
+impl LineRanges {
+    /// Parse a comma separated list of lines and ranges; invalid parts are
+    /// ignored.
+    pub fn parse(spec: &str) -> Self {
+        let ranges = spec
+            .trim_matches(|c| c == '{' || c == '}')
+            .split(',')
+            .filter_map(|part| {
+                let part = part.trim();
+                match part.split_once('-') {
+                    Some((start, end)) => Some(start.trim().parse().ok()?..=end.trim().parse().ok()?),
+                    None => {
+                        let line = part.parse().ok()?;
+                        Some(line..=line)
+                    }
+                }
+            })
+            .collect();
+        Self(ranges)
+    }
+
+    pub fn contains(&self, line: usize) -> bool {
+        self.0.iter().any(|range| range.contains(&line))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<&str> for LineRanges {
+    fn from(spec: &str) -> Self {
+        Self::parse(spec)
+    }
+}
+
+impl From<String> for LineRanges {
+    fn from(spec: String) -> Self {
+        Self::parse(&spec)
+    }
+}
+
+impl From<Vec<RangeInclusive<usize>>> for LineRanges {
+    fn from(ranges: Vec<RangeInclusive<usize>>) -> Self {
+        Self(ranges)
+    }
+}
+
+/// Marker at the start of a line in a diff-style code block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffMarker {
+    Added,
+    Removed,
+    Unchanged,
+}
+
+impl DiffMarker {
+    /// Split the one-character marker column (`+`, `-` or a space) off `line`.
+    pub fn split(line: &str) -> (Self, &str) {
+        let (marker, rest) = match line.chars().next() {
+            Some('+') => (DiffMarker::Added, &line[1..]),
+            Some('-') => (DiffMarker::Removed, &line[1..]),
+            Some(' ') => (DiffMarker::Unchanged, &line[1..]),
+            _ => (DiffMarker::Unchanged, line),
+        };
+        (marker, rest)
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            DiffMarker::Added => "+",
+            DiffMarker::Removed => "-",
+            DiffMarker::Unchanged => " ",
+        }
+    }
+
+    /// Background of the whole line.
+    pub fn line_class(&self) -> &'static str {
+        match self {
+            DiffMarker::Added => "bg-success/10",
+            DiffMarker::Removed => "bg-error/10",
+            DiffMarker::Unchanged => "",
+        }
+    }
+
+    /// Color of the marker in the gutter.
+    pub fn marker_class(&self) -> &'static str {
+        match self {
+            DiffMarker::Added => "text-success",
+            DiffMarker::Removed => "text-error",
+            DiffMarker::Unchanged => "text-base03",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tokens as `(kind, text)`, trimmed, without whitespace-only ones.
+    /// Adjacent plain tokens are merged, so `x y` stays one token.
+    fn tokens(language: Language, source: &str) -> Vec<(TokenKind, &str)> {
+        tokenize(language, source)
+            .into_iter()
+            .map(|token| (token.kind, token.text.trim()))
+            .filter(|(_, text)| !text.is_empty())
+            .collect()
+    }
+
+    fn kind_of(language: Language, source: &str, text: &str) -> TokenKind {
+        tokens(language, source)
+            .into_iter()
+            .find(|(_, token)| *token == text)
+            .unwrap_or_else(|| panic!("no token {text:?} in {source:?}"))
+            .0
+    }
+
+    #[test]
+    fn strings_keep_escaped_quotes() {
+        let source = r#"let s = "say \"hi\"";"#;
+        assert_eq!(kind_of(Language::Rust, source, r#""say \"hi\"""#), TokenKind::String);
+        assert_eq!(kind_of(Language::Toml, r"path = 'C:\dir'", r"'C:\dir'"), TokenKind::String);
+        assert_eq!(kind_of(Language::Json, r#"{"key": "value"}"#, r#""key""#), TokenKind::Property);
+        assert_eq!(kind_of(Language::Json, r#"{"key": "value"}"#, r#""value""#), TokenKind::String);
+    }
+
+    #[test]
+    fn comments_run_to_end_of_line_or_block() {
+        assert_eq!(
+            tokens(Language::Rust, "x // note\ny"),
+            [(TokenKind::Plain, "x"), (TokenKind::Comment, "// note"), (TokenKind::Plain, "y")]
+        );
+        assert_eq!(kind_of(Language::Nix, "/* a\nb */ x", "/* a\nb */"), TokenKind::Comment);
+        // `#` inside a shell word isn't a comment.
+        assert_eq!(kind_of(Language::Shell, "echo a#b # done", "# done"), TokenKind::Comment);
+        assert!(tokens(Language::Shell, "echo a#b").iter().all(|(kind, _)| *kind != TokenKind::Comment));
+    }
+
+    #[test]
+    fn rust_block_comments_nest() {
+        let source = "/* outer /* inner */ still outer */ fn";
+        assert_eq!(
+            tokens(Language::Rust, source),
+            [(TokenKind::Comment, "/* outer /* inner */ still outer */"), (TokenKind::Keyword, "fn")]
+        );
+    }
+
+    #[test]
+    fn raw_strings_end_at_matching_hashes() {
+        let source = r###"let s = r#"a "quoted" b"#; x"###;
+        assert_eq!(kind_of(Language::Rust, source, r###"r#"a "quoted" b"#"###), TokenKind::String);
+        assert_eq!(kind_of(Language::Rust, r#"r"\d+""#, r#"r"\d+""#), TokenKind::String);
+    }
+
+    #[test]
+    fn lifetimes_are_not_chars() {
+        let source = "fn f<'a>(x: &'a str) -> char { 'x' }";
+        assert_eq!(kind_of(Language::Rust, source, "'a"), TokenKind::Type);
+        assert_eq!(kind_of(Language::Rust, source, "'x'"), TokenKind::String);
+        assert_eq!(kind_of(Language::Rust, r"'\n'", r"'\n'"), TokenKind::String);
+    }
+
+    #[test]
+    fn multi_byte_input_round_trips() {
+        let source = "let café = \"naïve – ✓\"; // 日本語\n'é' 'λ";
+        let joined: String = tokenize(Language::Rust, source).iter().map(|token| token.text).collect();
+        assert_eq!(joined, source);
+        assert_eq!(kind_of(Language::Rust, source, "café"), TokenKind::Plain);
+        assert_eq!(kind_of(Language::Rust, source, "'é'"), TokenKind::String);
+        assert_eq!(kind_of(Language::Rust, source, "'λ"), TokenKind::Type);
+    }
+
+    #[test]
+    fn unterminated_tokens_run_to_end_of_input() {
+        let inputs = [
+            (Language::Rust, "\"open"),
+            (Language::Rust, "/* open /* nested */"),
+            (Language::Rust, "r#\"open\""),
+            (Language::Rust, "'"),
+            (Language::Rust, "'\\"),
+            (Language::Rust, "b'"),
+            (Language::Rust, "#[derive(Debug"),
+            (Language::Toml, "key = \"\"\"open"),
+            (Language::Toml, "[section"),
+            (Language::Nix, "''open"),
+            (Language::Shell, "echo ${open"),
+            (Language::Shell, "$"),
+            (Language::Cypher, "`open"),
+            (Language::Json, "{\"open"),
+        ];
+        for (language, source) in inputs {
+            let tokens = tokenize(language, source);
+            let joined: String = tokens.iter().map(|token| token.text).collect();
+            assert_eq!(joined, source, "{language:?}");
+            assert!(highlight_lines(language, source).iter().flatten().count() >= 1);
+        }
+    }
+
+    #[test]
+    fn highlight_lines_splits_multi_line_tokens() {
+        let lines = highlight_lines(Language::Rust, "/* a\r\nb */\nx\n");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], [Token { kind: TokenKind::Comment, text: "/* a" }]);
+        assert_eq!(lines[1], [Token { kind: TokenKind::Comment, text: "b */" }]);
+        assert_eq!(lines[2], [Token { kind: TokenKind::Plain, text: "x" }]);
+    }
+}
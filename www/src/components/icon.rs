@@ -0,0 +1,127 @@
+//! Inline SVG icons from the shared `assets/` directory.
+//!
+//! `build.rs` prepares the SVG files and each [`IconName`] variant embeds
+//! its file with `include_str!`, so adding a variant without a matching
+//! `assets/<file>.svg` fails to compile.
+
+use leptos::prelude::*;
+
+use crate::components::ids::unique_id;
+
+macro_rules! icons {
+    ($($variant:ident => $file:literal,)*) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum IconName {
+            $($variant,)*
+        }
+
+        impl IconName {
+            pub const ALL: &'static [IconName] = &[$(IconName::$variant,)*];
+
+            /// File name in `assets/`, without the extension.
+            pub fn file(&self) -> &'static str {
+                match self {
+                    $(IconName::$variant => $file,)*
+                }
+            }
+
+            pub fn view_box(&self) -> &'static str {
+                match self {
+                    $(IconName::$variant => include_str!(concat!(env!("OUT_DIR"), "/icons/", $file, ".viewbox")),)*
+                }
+            }
+
+            /// Markup inside the `<svg>` root.
+            fn body(&self) -> &'static str {
+                match self {
+                    $(IconName::$variant => include_str!(concat!(env!("OUT_DIR"), "/icons/", $file, ".svg")),)*
+                }
+            }
+        }
+    };
+}
+
+icons! {
+    Chat => "chat",
+    Command => "command",
+    Contact => "contact",
+    Dashboard => "dashboard",
+    Events => "events",
+    GitHub => "github",
+    GraphQl => "graphql",
+    Menu => "menu",
+    People => "people",
+    Science => "science",
+    Settings => "settings",
+    Tools => "tools",
+    Workflow => "workflow",
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconSize {
+    /// 1em, matching the surrounding text.
+    Inherit,
+    /// 12px
+    ExtraSmall,
+    /// 16px
+    Small,
+    /// 20px
+    #[default]
+    Medium,
+    /// 24px
+    Large,
+    /// 32px
+    ExtraLarge,
+}
+
+impl IconSize {
+    pub fn class(&self) -> &'static str {
+        match self {
+            IconSize::Inherit => "w-[1em] h-[1em]",
+            IconSize::ExtraSmall => "w-3 h-3",
+            IconSize::Small => "w-4 h-4",
+            IconSize::Medium => "w-5 h-5",
+            IconSize::Large => "w-6 h-6",
+            IconSize::ExtraLarge => "w-8 h-8",
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// An inline SVG icon drawn in `currentColor`, so it takes the text color
+/// of its context (set it with `text-*` classes).
+///
+/// Icons are decorative (`aria-hidden`) unless a `title` is given, in which
+/// case they are exposed as an image named by that title. Icon-only buttons
+/// should put their label on the button instead.
+#[component]
+pub fn Icon(
+    name: IconName,
+    #[prop(optional)] size: IconSize,
+    #[prop(optional, into)] title: Option<String>,
+    #[prop(optional, into)] class: Signal<String>,
+) -> impl IntoView {
+    let title_id = title.as_ref().map(|_| format!("{}-title", unique_id("icon")));
+    let markup = match (&title, &title_id) {
+        (Some(title), Some(id)) => format!("<title id=\"{}\">{}</title>{}", id, escape(title), name.body()),
+        _ => name.body().to_string(),
+    };
+    let labelled = title.is_some();
+
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox=name.view_box()
+            fill="currentColor"
+            class=move || format!("inline-block shrink-0 {} {}", size.class(), class.get())
+            role=labelled.then_some("img")
+            aria-labelledby=title_id
+            aria-hidden=(!labelled).then_some("true")
+            focusable="false"
+            inner_html=markup
+        ></svg>
+    }
+}
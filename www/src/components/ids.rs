@@ -0,0 +1,31 @@
+//! Unique ids for ARIA relationships (`aria-labelledby`, `aria-controls`,
+//! `for`) between elements a component renders.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A number no other call returns, for components that key state by
+/// instance (menus, the dialog stack) as well as deriving DOM ids from it.
+pub fn next_id() -> usize {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A DOM id of the form `<prefix>-<n>`, unique within the page.
+pub fn unique_id(prefix: &str) -> String {
+    format!("{}-{}", prefix, next_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_prefixed_and_distinct() {
+        let first = unique_id("tooltip");
+        let second = unique_id("tooltip");
+        assert!(first.starts_with("tooltip-"));
+        assert_ne!(first, second);
+        assert_ne!(next_id(), next_id());
+    }
+}
@@ -0,0 +1,153 @@
+//! Responsive images.
+//!
+//! The `imagegen` tool (run by Trunk before each build) encodes every raster
+//! image in `assets/` at several widths as AVIF, WebP and JPEG/PNG, and
+//! records its size, dominant color and a tiny blurred preview. `build.rs`
+//! compiles that into [`IMAGES`], so `Image` can emit `srcset`s and reserve
+//! the right space before anything has loaded. Images missing from the table
+//! (remote URLs, or a build without `imagegen`) are served as-is.
+
+use leptos::prelude::*;
+
+use crate::chunks::public_url;
+
+include!(concat!(env!("OUT_DIR"), "/images.rs"));
+
+/// An image in `assets/` with generated variants.
+#[derive(Debug, PartialEq)]
+pub struct ImageAsset {
+    /// File name in `assets/`, without the extension.
+    pub name: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// Dominant color as `#rrggbb`.
+    pub color: &'static str,
+    /// Extension of the non-AVIF/WebP variants: `jpg`, or `png` for images
+    /// with transparency.
+    pub fallback: &'static str,
+    /// Widths of the generated variants, ascending.
+    pub widths: &'static [u32],
+    /// Tiny WebP data URI.
+    pub placeholder: &'static str,
+}
+
+impl ImageAsset {
+    /// The entry for `assets/<name>.<ext>`, written relative to Trunk's
+    /// `public_url`, from the site root (`/assets/…`) or as a full URL.
+    pub fn find(src: &str) -> Option<&'static ImageAsset> {
+        let assets = public_url("assets/");
+        let file = src
+            .strip_prefix("assets/")
+            .or_else(|| src.strip_prefix("/assets/"))
+            .or_else(|| src.strip_prefix(assets.as_str()))?;
+        let name = file.rsplit_once('.').map_or(file, |(name, _)| name);
+        IMAGES.iter().find(|image| image.name == name)
+    }
+
+    pub fn url(&self, width: u32, ext: &str) -> String {
+        public_url(&format!("assets/generated/{}-{}.{}", self.name, width, ext))
+    }
+
+    pub fn srcset(&self, ext: &str) -> String {
+        self.widths.iter().map(|&width| format!("{} {}w", self.url(width, ext), width)).collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// What to show in an image's box while it loads.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Placeholder {
+    /// A blurred low-resolution preview over the dominant color.
+    #[default]
+    Blur,
+    /// The dominant color.
+    Color,
+    /// Nothing.
+    None,
+}
+
+/// A responsive, lazily loaded image.
+///
+/// `alt` is required: describe the image, or pass `""` for purely decorative
+/// images. For images from `assets/`, the box keeps the image's aspect ratio
+/// so nothing shifts when it loads; for others pass `width` and `height`.
+/// `sizes` tells the browser how wide the image is displayed (default
+/// `100vw`) so it can pick the smallest sufficient variant. Set `priority`
+/// for images visible on first paint to skip lazy loading.
+#[component]
+pub fn Image(
+    #[prop(into)] src: String,
+    #[prop(into)] alt: String,
+    #[prop(optional, into)] sizes: Option<String>,
+    #[prop(optional)] width: Option<u32>,
+    #[prop(optional)] height: Option<u32>,
+    #[prop(optional)] placeholder: Placeholder,
+    #[prop(optional)] priority: bool,
+    #[prop(optional, into)] class: Signal<String>,
+) -> impl IntoView {
+    let asset = ImageAsset::find(&src);
+    let size = asset.map(|asset| (asset.width, asset.height)).or(width.zip(height));
+    let sizes = sizes.unwrap_or_else(|| "100vw".to_string());
+    let loaded = RwSignal::new(false);
+
+    let (src, srcset) = match asset {
+        Some(asset) => {
+            let largest = asset.widths.last().copied().unwrap_or(asset.width);
+            (asset.url(largest, asset.fallback), Some(asset.srcset(asset.fallback)))
+        }
+        None => (src, None),
+    };
+    let sources = asset.map(|asset| {
+        view! {
+            <source type="image/avif" srcset=asset.srcset("avif") sizes=sizes.clone() />
+            <source type="image/webp" srcset=asset.srcset("webp") sizes=sizes.clone() />
+        }
+    });
+
+    let color = asset.map(|asset| asset.color).filter(|_| placeholder != Placeholder::None);
+    let preview = asset.map(|asset| asset.placeholder).filter(|_| placeholder == Placeholder::Blur);
+    let layer = (color.is_some() || preview.is_some()).then(|| {
+        let mut style = format!("background-color: {};", color.unwrap_or("transparent"));
+        if let Some(preview) = preview {
+            style.push_str(&format!(" background-image: url({}); background-size: cover;", preview));
+        }
+        view! {
+            <div
+                class="absolute inset-0 transition-opacity duration-500 motion-reduce:transition-none"
+                class=("blur-lg", preview.is_some())
+                class=("scale-110", preview.is_some())
+                class:opacity-0=move || loaded.get()
+                style=style
+                aria-hidden="true"
+            ></div>
+        }
+    });
+
+    let img_class = if size.is_some() { "absolute inset-0 w-full h-full object-cover" } else { "block w-full h-auto" };
+
+    view! {
+        <div
+            class=move || format!("relative overflow-hidden {}", class.get())
+            style=size.map(|(width, height)| format!("aspect-ratio: {} / {};", width, height))
+        >
+            {layer}
+            <picture>
+                {sources}
+                <img
+                    src=src
+                    srcset=srcset
+                    sizes=sizes
+                    alt=alt
+                    width=size.map(|(width, _)| width)
+                    height=size.map(|(_, height)| height)
+                    loading=if priority { "eager" } else { "lazy" }
+                    decoding="async"
+                    fetchpriority=priority.then_some("high")
+                    class=format!("{} transition-opacity duration-500 motion-reduce:transition-none", img_class)
+                    class:opacity-0=move || !loaded.get()
+                    on:load=move |_| loaded.set(true)
+                    on:error=move |_| loaded.set(true)
+                />
+            </picture>
+        </div>
+    }
+}
@@ -0,0 +1,504 @@
+//! Form controls in Crystalline Neon styling.
+//!
+//! Every control is bound to a signal, takes a `label`, optional `help` text
+//! and a reactive `error`, and wires them up with `aria-describedby` and
+//! `aria-invalid`. Sizes reuse [`ButtonSize`] so inputs line up with buttons
+//! in the same row.
+
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::components::button::ButtonSize;
+use crate::components::ids::unique_id;
+use crate::components::roving::Orientation;
+
+/// Element ids of a field and its help and error messages.
+#[derive(Clone, Debug)]
+struct FieldIds {
+    input: String,
+    help: String,
+    error: String,
+}
+
+impl FieldIds {
+    /// Ids derived from `id`, or from a generated one.
+    fn new(id: Option<String>) -> Self {
+        let input = id.unwrap_or_else(|| unique_id("field"));
+        Self { help: format!("{input}-help"), error: format!("{input}-error"), input }
+    }
+
+    /// `aria-describedby` value: the help text, then the error if present.
+    fn described_by(&self, has_help: bool, error: Signal<Option<String>>) -> impl Fn() -> Option<String> + Send + Sync + 'static {
+        let ids = self.clone();
+        move || {
+            let mut described_by = Vec::new();
+            if has_help {
+                described_by.push(ids.help.as_str());
+            }
+            if error.with(Option::is_some) {
+                described_by.push(ids.error.as_str());
+            }
+            (!described_by.is_empty()).then(|| described_by.join(" "))
+        }
+    }
+}
+
+fn invalid(error: Signal<Option<String>>) -> impl Fn() -> Option<&'static str> + Copy + Send + Sync + 'static {
+    move || error.with(Option::is_some).then_some("true")
+}
+
+/// Classes shared by text-like controls.
+fn input_class(size: ButtonSize, error: bool, extra: &str) -> String {
+    let size = match size {
+        ButtonSize::Small => "input-sm",
+        ButtonSize::Medium => "",
+        ButtonSize::Large => "input-lg",
+    };
+    format!("input w-full {} {} {}", size, if error { "input-invalid" } else { "" }, extra)
+}
+
+/// One choice in a [`Select`] or [`RadioGroup`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectOption {
+    pub value: String,
+    pub label: String,
+    pub disabled: bool,
+}
+
+impl SelectOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self { value: value.into(), label: label.into(), disabled: false }
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+#[component]
+fn FieldLabel(#[prop(into)] for_id: String, label: String, required: bool) -> impl IntoView {
+    view! {
+        <label for=for_id class="text-sm font-outfit font-semibold text-text-primary">
+            {label}
+            {required.then(|| view! { <span class="ml-0.5 text-error" aria-hidden="true">"*"</span> })}
+        </label>
+    }
+}
+
+/// Help text and the error message. The error region is always rendered so
+/// screen readers announce errors as they appear.
+#[component]
+fn FieldMessages(ids: FieldIds, help: Option<String>, error: Signal<Option<String>>) -> impl IntoView {
+    view! {
+        {help.map(|help| view! {
+            <p id=ids.help class="text-xs font-inter text-text-muted">{help}</p>
+        })}
+        <p
+            id=ids.error
+            class="text-xs font-inter text-error"
+            class:hidden=move || error.with(Option::is_none)
+            aria-live="polite"
+        >
+            {move || error.get()}
+        </p>
+    }
+}
+
+/// Single-line text input (`text`, `email`, `password`, `search`, ...).
+#[component]
+pub fn TextInput(
+    value: RwSignal<String>,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(default = "text")] input_type: &'static str,
+    #[prop(optional, into)] placeholder: Option<String>,
+    #[prop(optional, into)] autocomplete: Option<String>,
+    #[prop(optional, into)] help: Option<String>,
+    #[prop(into, optional)] error: Signal<Option<String>>,
+    #[prop(optional)] required: bool,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] on_blur: Option<Callback<()>>,
+) -> impl IntoView {
+    let ids = FieldIds::new(id);
+
+    view! {
+        <div class=move || format!("flex flex-col gap-1 {}", class.get())>
+            <FieldLabel for_id=ids.input.clone() label=label required=required />
+            <input
+                id=ids.input.clone()
+                type=input_type
+                placeholder=placeholder
+                autocomplete=autocomplete
+                required=required
+                disabled=move || disabled.get()
+                class=move || input_class(size.get(), error.with(Option::is_some), "")
+                aria-invalid=invalid(error)
+                aria-describedby=ids.described_by(help.is_some(), error)
+                prop:value=move || value.get()
+                on:input=move |ev| value.set(event_target_value(&ev))
+                on:blur=move |_| {
+                    if let Some(on_blur) = on_blur {
+                        on_blur.run(());
+                    }
+                }
+            />
+            <FieldMessages ids=ids help=help error=error />
+        </div>
+    }
+}
+
+/// Multi-line text input that grows with its content when `auto_resize` is on.
+#[component]
+pub fn TextArea(
+    value: RwSignal<String>,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(default = 3)] rows: u32,
+    #[prop(default = true)] auto_resize: bool,
+    #[prop(optional, into)] placeholder: Option<String>,
+    #[prop(optional, into)] help: Option<String>,
+    #[prop(into, optional)] error: Signal<Option<String>>,
+    #[prop(optional)] required: bool,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] on_blur: Option<Callback<()>>,
+) -> impl IntoView {
+    let ids = FieldIds::new(id);
+    let textarea = NodeRef::<html::Textarea>::new();
+
+    // Resize after every change, including programmatic ones such as a reset.
+    Effect::new(move |_| {
+        value.track();
+        if let (true, Some(el)) = (auto_resize, textarea.get()) {
+            let style = web_sys::HtmlElement::style(&el);
+            let _ = style.set_property("height", "auto");
+            let _ = style.set_property("height", &format!("{}px", el.scroll_height()));
+        }
+    });
+
+    view! {
+        <div class=move || format!("flex flex-col gap-1 {}", class.get())>
+            <FieldLabel for_id=ids.input.clone() label=label required=required />
+            <textarea
+                node_ref=textarea
+                id=ids.input.clone()
+                rows=rows
+                placeholder=placeholder
+                required=required
+                disabled=move || disabled.get()
+                class=move || input_class(size.get(), error.with(Option::is_some), if auto_resize { "resize-none overflow-hidden" } else { "resize-y" })
+                aria-invalid=invalid(error)
+                aria-describedby=ids.described_by(help.is_some(), error)
+                prop:value=move || value.get()
+                on:input=move |ev| value.set(event_target_value(&ev))
+                on:blur=move |_| {
+                    if let Some(on_blur) = on_blur {
+                        on_blur.run(());
+                    }
+                }
+            ></textarea>
+            <FieldMessages ids=ids help=help error=error />
+        </div>
+    }
+}
+
+/// Native select, styled like the other inputs. With `placeholder` an empty,
+/// unselectable first option prompts for a choice.
+#[component]
+pub fn Select(
+    value: RwSignal<String>,
+    options: Vec<SelectOption>,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional, into)] placeholder: Option<String>,
+    #[prop(optional, into)] help: Option<String>,
+    #[prop(into, optional)] error: Signal<Option<String>>,
+    #[prop(optional)] required: bool,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] on_blur: Option<Callback<()>>,
+) -> impl IntoView {
+    let ids = FieldIds::new(id);
+
+    view! {
+        <div class=move || format!("flex flex-col gap-1 {}", class.get())>
+            <FieldLabel for_id=ids.input.clone() label=label required=required />
+            <div class="relative">
+                <select
+                    id=ids.input.clone()
+                    required=required
+                    disabled=move || disabled.get()
+                    class=move || input_class(size.get(), error.with(Option::is_some), "appearance-none pr-10 cursor-pointer")
+                    aria-invalid=invalid(error)
+                    aria-describedby=ids.described_by(help.is_some(), error)
+                    prop:value=move || value.get()
+                    on:change=move |ev| value.set(event_target_value(&ev))
+                    on:blur=move |_| {
+                        if let Some(on_blur) = on_blur {
+                            on_blur.run(());
+                        }
+                    }
+                >
+                    {placeholder.map(|placeholder| view! {
+                        <option value="" disabled=true selected=move || value.with(String::is_empty)>{placeholder}</option>
+                    })}
+                    {options
+                        .into_iter()
+                        .map(|option| {
+                            let selected_value = option.value.clone();
+                            view! {
+                                <option
+                                    value=option.value
+                                    disabled=option.disabled
+                                    selected=move || value.with(|value| *value == selected_value)
+                                >
+                                    {option.label}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+                <span class="pointer-events-none absolute inset-y-0 right-3 flex items-center text-text-muted" aria-hidden="true">
+                    "▾"
+                </span>
+            </div>
+            <FieldMessages ids=ids help=help error=error />
+        </div>
+    }
+}
+
+/// Checkbox with its label beside it.
+#[component]
+pub fn Checkbox(
+    checked: RwSignal<bool>,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional, into)] help: Option<String>,
+    #[prop(into, optional)] error: Signal<Option<String>>,
+    #[prop(optional)] required: bool,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] on_blur: Option<Callback<()>>,
+) -> impl IntoView {
+    let ids = FieldIds::new(id);
+
+    view! {
+        <div class=move || format!("flex flex-col gap-1 {}", class.get())>
+            <div class="flex items-center gap-2">
+                <input
+                    id=ids.input.clone()
+                    type="checkbox"
+                    required=required
+                    disabled=move || disabled.get()
+                    class="w-4 h-4 rounded border-border accent-accent cursor-pointer focus:outline-none focus-visible:ring-2 focus-visible:ring-accent focus-visible:shadow-[0_0_8px_2px_theme(colors.glow)] disabled:cursor-not-allowed disabled:opacity-50"
+                    aria-invalid=invalid(error)
+                    aria-describedby=ids.described_by(help.is_some(), error)
+                    prop:checked=move || checked.get()
+                    on:change=move |ev| checked.set(event_target_checked(&ev))
+                    on:blur=move |_| {
+                        if let Some(on_blur) = on_blur {
+                            on_blur.run(());
+                        }
+                    }
+                />
+                <FieldLabel for_id=ids.input.clone() label=label required=required />
+            </div>
+            <FieldMessages ids=ids help=help error=error />
+        </div>
+    }
+}
+
+/// A set of mutually exclusive options. Native radios give arrow-key
+/// navigation and a single tab stop for free.
+#[component]
+pub fn RadioGroup(
+    value: RwSignal<String>,
+    options: Vec<SelectOption>,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional, into)] help: Option<String>,
+    #[prop(into, optional)] error: Signal<Option<String>>,
+    #[prop(optional)] required: bool,
+    #[prop(optional)] orientation: Orientation,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(optional, into)] class: Signal<String>,
+    /// Runs when focus leaves the group, not when it moves between options.
+    #[prop(optional, into)] on_blur: Option<Callback<()>>,
+) -> impl IntoView {
+    let ids = FieldIds::new(id);
+    let name = ids.input.clone();
+    let described_by = ids.described_by(help.is_some(), error);
+    let direction = match orientation {
+        Orientation::Horizontal => "flex-row flex-wrap gap-4",
+        Orientation::Vertical => "flex-col gap-2",
+    };
+    let on_focusout = move |ev: ev::FocusEvent| {
+        let group = ev.current_target().and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        let next = ev.related_target().and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        let inside = matches!((group, next), (Some(group), Some(next)) if group.contains(Some(&next)));
+        if let (false, Some(on_blur)) = (inside, on_blur) {
+            on_blur.run(());
+        }
+    };
+
+    view! {
+        <fieldset
+            id=name.clone()
+            role="radiogroup"
+            aria-required=required.then_some("true")
+            aria-invalid=invalid(error)
+            aria-describedby=described_by
+            disabled=move || disabled.get()
+            class=move || format!("flex flex-col gap-1 {}", class.get())
+            on:focusout=on_focusout
+        >
+            <legend class="mb-1 text-sm font-outfit font-semibold text-text-primary">
+                {label}
+                {required.then(|| view! { <span class="ml-0.5 text-error" aria-hidden="true">"*"</span> })}
+            </legend>
+            <div class=format!("flex {}", direction)>
+                {options
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, option)| {
+                        let option_id = format!("{}-{}", name, index);
+                        let label_for = option_id.clone();
+                        let option_value = option.value.clone();
+                        let change_value = option.value.clone();
+                        view! {
+                            <label
+                                for=label_for
+                                class="inline-flex items-center gap-2 text-sm font-inter text-text-primary cursor-pointer has-[:disabled]:cursor-not-allowed has-[:disabled]:opacity-50"
+                            >
+                                <input
+                                    id=option_id
+                                    type="radio"
+                                    name=name.clone()
+                                    value=option.value
+                                    required=required
+                                    disabled=option.disabled
+                                    class="w-4 h-4 accent-accent focus:outline-none focus-visible:ring-2 focus-visible:ring-accent focus-visible:shadow-[0_0_8px_2px_theme(colors.glow)]"
+                                    prop:checked=move || value.with(|value| *value == option_value)
+                                    on:change=move |_| value.set(change_value.clone())
+                                />
+                                {option.label}
+                            </label>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <FieldMessages ids=ids help=help error=error />
+        </fieldset>
+    }
+}
+
+/// On/off switch (`role="switch"`), for settings that apply immediately.
+#[component]
+pub fn Switch(
+    checked: RwSignal<bool>,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional, into)] help: Option<String>,
+    #[prop(into, optional)] error: Signal<Option<String>>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] on_blur: Option<Callback<()>>,
+) -> impl IntoView {
+    let ids = FieldIds::new(id);
+
+    view! {
+        <div class=move || format!("flex flex-col gap-1 {}", class.get())>
+            <div class="flex items-center gap-3">
+                <button
+                    id=ids.input.clone()
+                    type="button"
+                    role="switch"
+                    aria-checked=move || checked.get().to_string()
+                    aria-invalid=invalid(error)
+                    aria-describedby=ids.described_by(help.is_some(), error)
+                    disabled=move || disabled.get()
+                    class=move || format!(
+                        "relative inline-flex h-6 w-11 shrink-0 items-center rounded-full border border-border transition-all duration-300 motion-reduce:transition-none focus:outline-none focus-visible:ring-2 focus-visible:ring-accent disabled:opacity-50 disabled:cursor-not-allowed {}",
+                        if checked.get() { "bg-accent shadow-[0_0_8px_2px_theme(colors.glow)]" } else { "bg-surface" },
+                    )
+                    on:click=move |_| checked.update(|checked| *checked = !*checked)
+                    on:blur=move |_| {
+                        if let Some(on_blur) = on_blur {
+                            on_blur.run(());
+                        }
+                    }
+                >
+                    <span
+                        class="inline-block h-4 w-4 rounded-full bg-base07 shadow transition-transform duration-300 motion-reduce:transition-none"
+                        class:translate-x-6=move || checked.get()
+                        class:translate-x-1=move || !checked.get()
+                        aria-hidden="true"
+                    ></span>
+                </button>
+                <FieldLabel for_id=ids.input.clone() label=label required=false />
+            </div>
+            <FieldMessages ids=ids help=help error=error />
+        </div>
+    }
+}
+
+/// Range slider with the current value shown beside the label.
+#[component]
+pub fn Slider(
+    value: RwSignal<f64>,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(default = 0.0)] min: f64,
+    #[prop(default = 100.0)] max: f64,
+    #[prop(default = 1.0)] step: f64,
+    #[prop(default = true)] show_value: bool,
+    #[prop(optional, into)] help: Option<String>,
+    #[prop(into, optional)] error: Signal<Option<String>>,
+    #[prop(into, optional)] disabled: Signal<bool>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] on_blur: Option<Callback<()>>,
+) -> impl IntoView {
+    let ids = FieldIds::new(id);
+    let on_input = move |ev: ev::Event| {
+        if let Ok(next) = event_target_value(&ev).parse::<f64>() {
+            value.set(next);
+        }
+    };
+
+    view! {
+        <div class=move || format!("flex flex-col gap-1 {}", class.get())>
+            <div class="flex items-center justify-between gap-4">
+                <FieldLabel for_id=ids.input.clone() label=label required=false />
+                {show_value.then(|| view! {
+                    <output for=ids.input.clone() class="text-sm font-mono text-accent">{move || value.get()}</output>
+                })}
+            </div>
+            <input
+                id=ids.input.clone()
+                type="range"
+                min=min
+                max=max
+                step=step
+                disabled=move || disabled.get()
+                class="w-full accent-accent cursor-pointer focus:outline-none focus-visible:ring-2 focus-visible:ring-accent disabled:opacity-50 disabled:cursor-not-allowed"
+                aria-invalid=invalid(error)
+                aria-describedby=ids.described_by(help.is_some(), error)
+                prop:value=move || value.get().to_string()
+                on:input=on_input
+                on:blur=move |_| {
+                    if let Some(on_blur) = on_blur {
+                        on_blur.run(());
+                    }
+                }
+            />
+            <FieldMessages ids=ids help=help error=error />
+        </div>
+    }
+}
@@ -1,7 +1,7 @@
 use leptos::*;
 use leptos::prelude::*;
 use crate::components::theme::{Theme, ThemeContext};
-use crate::components::Navigation;
+use crate::components::{Footer, Navigation};
 use leptos_router::components::Outlet;
 
 /// Main layout component for the application
@@ -32,11 +32,7 @@ pub fn MainLayout(
                 <Outlet/>
             </main>
             
-            <footer class="bg-base01 text-base05">
-                <div class="container mx-auto p-4 text-center">
-                    <p>"W3 Memory Bank © 2023"</p>
-                </div>
-            </footer>
+            <Footer />
         </div>
     }
 } 
\ No newline at end of file
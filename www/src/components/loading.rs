@@ -0,0 +1,247 @@
+//! Loading states: `Skeleton` placeholders, `Spinner`, and `Progress` /
+//! `ProgressRing` for work with or without a known amount.
+//!
+//! Animations (the skeleton shimmer, spinning and the indeterminate sweep in
+//! `styles.css`) stop under `prefers-reduced-motion`.
+
+use leptos::prelude::*;
+
+use crate::components::ids::unique_id;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SkeletonShape {
+    /// Lines of text at the current font size.
+    #[default]
+    Text,
+    /// A block, such as an image or card. Defaults to full width, 6rem tall.
+    Rect,
+    /// An avatar. Defaults to 2.5rem.
+    Circle,
+}
+
+/// A placeholder in the shape of content that is still loading.
+///
+/// Skeletons are hidden from assistive technology; mark the region they stand
+/// in for with `aria-busy="true"` instead. Size them with `class`.
+#[component]
+pub fn Skeleton(
+    #[prop(optional)] shape: SkeletonShape,
+    /// Number of lines for `SkeletonShape::Text`; the last of several is
+    /// shorter, like the end of a paragraph.
+    #[prop(default = 1)]
+    lines: usize,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    match shape {
+        SkeletonShape::Text => view! {
+            <div class=format!("flex flex-col gap-2 {}", class) aria-hidden="true">
+                {(0..lines.max(1))
+                    .map(|line| {
+                        let last = lines > 1 && line == lines - 1;
+                        view! { <div class="skeleton h-[0.8em] rounded" class=("w-3/5", last)></div> }
+                    })
+                    .collect_view()}
+            </div>
+        }
+        .into_any(),
+        SkeletonShape::Rect => {
+            let size = if class.is_empty() { "w-full h-24" } else { "" };
+            view! { <div class=format!("skeleton rounded-md {} {}", size, class) aria-hidden="true"></div> }.into_any()
+        }
+        SkeletonShape::Circle => {
+            let size = if class.is_empty() { "w-10" } else { "" };
+            view! { <div class=format!("skeleton rounded-full aspect-square {} {}", size, class) aria-hidden="true"></div> }
+                .into_any()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SpinnerSize {
+    /// 16px
+    Small,
+    /// 24px
+    #[default]
+    Medium,
+    /// 40px
+    Large,
+}
+
+impl SpinnerSize {
+    pub fn class(&self) -> &'static str {
+        match self {
+            SpinnerSize::Small => "w-4 h-4 border-2",
+            SpinnerSize::Medium => "w-6 h-6 border-2",
+            SpinnerSize::Large => "w-10 h-10 border-4",
+        }
+    }
+}
+
+/// A spinning ring for short waits of unknown length, announced as a status
+/// with `label` (default "Loading…").
+#[component]
+pub fn Spinner(
+    #[prop(optional)] size: SpinnerSize,
+    #[prop(optional, into)] label: Option<String>,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    view! {
+        <span role="status" class=format!("inline-flex {}", class)>
+            <span
+                class=format!(
+                    "{} rounded-full border-base02 border-t-accent animate-spin motion-reduce:animate-none",
+                    size.class(),
+                )
+                aria-hidden="true"
+            ></span>
+            <span class="sr-only">{label.unwrap_or_else(|| "Loading…".to_string())}</span>
+        </span>
+    }
+}
+
+/// `value / max` clamped to `0.0..=1.0`; 0 when either isn't usable.
+fn fraction(value: f64, max: f64) -> f64 {
+    if max > 0.0 && !value.is_nan() {
+        (value / max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// `value` clamped to `0..=max`, for `aria-valuenow`. Unlike `f64::clamp`
+/// this doesn't panic on a negative or NaN `max`.
+fn value_now(value: f64, max: f64) -> f64 {
+    fraction(value, max) * max.max(0.0)
+}
+
+/// A progress bar.
+///
+/// `value` is out of `max` (default 100); `None` shows an indeterminate
+/// sweep for work whose length is unknown. `label` names the bar and is shown
+/// above it unless `hide_label` is set; `show_value` adds the percentage.
+#[component]
+pub fn Progress(
+    #[prop(into, optional)] value: Signal<Option<f64>>,
+    #[prop(default = 100.0)] max: f64,
+    #[prop(into)] label: String,
+    #[prop(optional)] hide_label: bool,
+    #[prop(optional)] show_value: bool,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    let label_id = format!("{}-label", unique_id("progress"));
+    let percent = move || value.get().map(|value| (fraction(value, max) * 100.0).round());
+
+    view! {
+        <div class=format!("flex flex-col gap-1.5 {}", class)>
+            <div class="flex justify-between gap-4 text-sm font-inter" class:sr-only=hide_label>
+                <span id=label_id.clone() class="text-text-secondary">{label}</span>
+                {show_value.then(|| view! {
+                    <span class="font-mono text-text-muted" aria-hidden="true">
+                        {move || percent().map(|percent| format!("{}%", percent))}
+                    </span>
+                })}
+            </div>
+            <div
+                role="progressbar"
+                aria-labelledby=label_id
+                aria-valuemin="0"
+                aria-valuemax=max.to_string()
+                aria-valuenow=move || value.get().map(|value| value_now(value, max).to_string())
+                aria-valuetext=move || percent().map(|percent| format!("{}%", percent))
+                class="relative h-2 rounded-full bg-base02 overflow-hidden"
+            >
+                {move || match percent() {
+                    Some(percent) => view! {
+                        <div
+                            class="h-full rounded-full bg-accent shadow-[0_0_8px_2px_theme(colors.glow)] transition-[width] duration-300 motion-reduce:transition-none"
+                            style=format!("width: {}%;", percent)
+                        ></div>
+                    }
+                    .into_any(),
+                    None => view! { <div class="progress-indeterminate rounded-full bg-accent"></div> }.into_any(),
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// A circular progress indicator, for compact spaces.
+///
+/// Takes `value`/`max` like `Progress`, with `None` spinning an open arc.
+/// `label` is its accessible name; `show_value` prints the percentage in the
+/// middle. `size` is the diameter in pixels.
+#[component]
+pub fn ProgressRing(
+    #[prop(into, optional)] value: Signal<Option<f64>>,
+    #[prop(default = 100.0)] max: f64,
+    #[prop(into)] label: String,
+    #[prop(optional)] show_value: bool,
+    #[prop(default = 48)] size: u32,
+    #[prop(default = 4)] stroke: u32,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    // A stroke at least as wide as the ring leaves no radius to draw.
+    let radius = ((size as f64 - stroke as f64) / 2.0).max(0.0);
+    let circumference = 2.0 * std::f64::consts::PI * radius;
+    let center = size as f64 / 2.0;
+    let percent = move || value.get().map(|value| (fraction(value, max) * 100.0).round());
+    let offset = move || match value.get() {
+        Some(value) => circumference * (1.0 - fraction(value, max)),
+        None => circumference * 0.75,
+    };
+
+    view! {
+        <div
+            role="progressbar"
+            aria-label=label
+            aria-valuemin="0"
+            aria-valuemax=max.to_string()
+            aria-valuenow=move || value.get().map(|value| value_now(value, max).to_string())
+            aria-valuetext=move || percent().map(|percent| format!("{}%", percent))
+            class=format!("relative inline-flex items-center justify-center shrink-0 {}", class)
+            style=format!("width: {}px; height: {}px;", size, size)
+        >
+            <svg
+                viewBox=format!("0 0 {} {}", size, size)
+                class="w-full h-full -rotate-90"
+                class=("animate-spin", move || value.get().is_none())
+                class=("motion-reduce:animate-none", move || value.get().is_none())
+                aria-hidden="true"
+            >
+                <circle cx=center cy=center r=radius fill="none" stroke-width=stroke class="stroke-base02" />
+                <circle
+                    cx=center
+                    cy=center
+                    r=radius
+                    fill="none"
+                    stroke-width=stroke
+                    stroke-linecap="round"
+                    stroke-dasharray=circumference
+                    stroke-dashoffset=offset
+                    class="stroke-accent transition-[stroke-dashoffset] duration-300 motion-reduce:transition-none"
+                />
+            </svg>
+            {show_value.then(|| view! {
+                <span class="absolute text-xs font-mono text-text-primary" aria-hidden="true">
+                    {move || percent().map(|percent| format!("{}%", percent))}
+                </span>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_now_tolerates_unusable_bounds() {
+        assert_eq!(value_now(50.0, 100.0), 50.0);
+        assert_eq!(value_now(150.0, 100.0), 100.0);
+        assert_eq!(value_now(-5.0, 100.0), 0.0);
+        assert_eq!(value_now(5.0, -1.0), 0.0);
+        assert_eq!(value_now(5.0, f64::NAN), 0.0);
+        assert_eq!(value_now(f64::NAN, 100.0), 0.0);
+        assert_eq!(fraction(5.0, 0.0), 0.0);
+    }
+}
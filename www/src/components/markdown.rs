@@ -0,0 +1,730 @@
+//! Markdown rendered with the design-system components.
+//!
+//! Source is parsed with `pulldown-cmark` (CommonMark plus GFM tables, task
+//! lists, strikethrough and footnotes) into a small owned tree, which is then
+//! turned into `Heading`, `Body`, `InlineCode`, `CodeBlock` and friends rather
+//! than an HTML string, so the output picks up theme styling and never goes
+//! through `inner_html` unless the content is trusted.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag};
+
+use crate::components::code_block::CodeBlock;
+use crate::components::highlight::LineRanges;
+use crate::components::mermaid::MermaidDiagram;
+use crate::components::typography::{Body, Heading, HeadingLevel, InlineCode, INLINE_CODE_CLASS};
+
+/// How much a Markdown source is trusted.
+///
+/// `Untrusted` is the default and is meant for anything a visitor typed:
+/// raw HTML is shown as literal text, links are limited to `http(s)`,
+/// `mailto` and relative URLs (and marked `nofollow ugc`), and only
+/// same-site images load, and `mermaid` blocks are shown as code rather than
+/// fetching the diagram renderer from its CDN, so content can't run script or
+/// make the page contact third parties. `Trusted` is for our own content
+/// (`memory-bank/*.md`, posts, docs): raw HTML is rendered, remote images
+/// are allowed and diagrams are drawn. Script URLs are refused under both
+/// policies.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MarkdownPolicy {
+    Trusted,
+    #[default]
+    Untrusted,
+}
+
+impl MarkdownPolicy {
+    /// `url` if it may be used as a link target under this policy.
+    pub fn link_url<'a>(&self, url: &'a str) -> Option<&'a str> {
+        match url_scheme(url).as_deref() {
+            None => Some(url),
+            Some("http" | "https" | "mailto") => Some(url),
+            Some("tel") if *self == MarkdownPolicy::Trusted => Some(url),
+            _ => None,
+        }
+    }
+
+    /// `url` if it may be loaded as an image under this policy.
+    pub fn image_url<'a>(&self, url: &'a str) -> Option<&'a str> {
+        match (self, url_scheme(url).as_deref()) {
+            (_, None) if !is_network_path(url) => Some(url),
+            (MarkdownPolicy::Trusted, Some("http" | "https")) => Some(url),
+            _ => None,
+        }
+    }
+}
+
+/// `url` as a browser reads it: whitespace and control characters dropped
+/// (`java\tscript:` is still `javascript:`) and `\` taken as `/`, so
+/// `/\host` is the network path `//host`.
+fn normalize_url(url: &str) -> String {
+    url.chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .map(|c| if c == '\\' { '/' } else { c })
+        .collect()
+}
+
+/// The lowercased scheme of `url`, or `None` for relative URLs.
+fn url_scheme(url: &str) -> Option<String> {
+    let cleaned = normalize_url(url);
+    let end = cleaned.find([':', '/', '?', '#'])?;
+    (cleaned[end..].starts_with(':') && end > 0).then(|| cleaned[..end].to_ascii_lowercase())
+}
+
+/// Whether `url` is scheme-relative (`//host/…`), i.e. on another site.
+fn is_network_path(url: &str) -> bool {
+    url_scheme(url).is_none() && normalize_url(url).starts_with("//")
+}
+
+/// Whether a link to `url` leaves the site.
+fn is_external(url: &str) -> bool {
+    url_scheme(url).is_some_and(|scheme| scheme.starts_with("http")) || is_network_path(url)
+}
+
+/// URL fragment for a heading, GitHub style: lowercase, punctuation dropped,
+/// spaces turned into dashes.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.trim().chars() {
+        if c.is_alphanumeric() || c == '_' {
+            slug.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+#[derive(Clone, Debug)]
+enum Element {
+    Paragraph,
+    Heading { level: HeadingLevel, id: String },
+    BlockQuote,
+    CodeBlock { info: String },
+    HtmlBlock,
+    List { start: Option<u64> },
+    Item,
+    FootnoteDefinition(String),
+    Table(Vec<Alignment>),
+    TableHead,
+    TableRow,
+    TableCell,
+    Emphasis,
+    Strong,
+    Strikethrough,
+    Link { url: String, title: String },
+    Image { url: String, title: String },
+    /// Anything else is rendered as its children.
+    Other,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Element(Element, Vec<Node>),
+    Text(String),
+    Code(String),
+    Html(String),
+    FootnoteReference(String),
+    TaskListMarker(bool),
+    SoftBreak,
+    HardBreak,
+    Rule,
+}
+
+/// A parsed Markdown document, footnote definitions split out so they can be
+/// listed at the end in reference order.
+#[derive(Clone, Debug, Default)]
+struct Document {
+    blocks: Vec<Node>,
+    footnotes: Vec<(String, Vec<Node>)>,
+    footnote_order: Vec<String>,
+}
+
+fn text_content(nodes: &[Node]) -> String {
+    let mut text = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(t) | Node::Code(t) => text.push_str(t),
+            Node::Element(_, children) => text.push_str(&text_content(children)),
+            Node::SoftBreak | Node::HardBreak => text.push(' '),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn heading_level(level: pulldown_cmark::HeadingLevel) -> HeadingLevel {
+    match level {
+        pulldown_cmark::HeadingLevel::H1 => HeadingLevel::H1,
+        pulldown_cmark::HeadingLevel::H2 => HeadingLevel::H2,
+        pulldown_cmark::HeadingLevel::H3 => HeadingLevel::H3,
+        pulldown_cmark::HeadingLevel::H4 => HeadingLevel::H4,
+        pulldown_cmark::HeadingLevel::H5 => HeadingLevel::H5,
+        pulldown_cmark::HeadingLevel::H6 => HeadingLevel::H6,
+    }
+}
+
+fn parse(source: &str) -> Document {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH;
+
+    let mut document = Document::default();
+    let mut slugs: HashMap<String, usize> = HashMap::new();
+    // Open elements and the children collected for each so far.
+    let mut stack: Vec<(Element, Vec<Node>)> = vec![(Element::Other, Vec::new())];
+
+    for event in Parser::new_ext(source, options) {
+        let node = match event {
+            Event::Start(tag) => {
+                let element = match tag {
+                    Tag::Paragraph => Element::Paragraph,
+                    Tag::Heading { level, .. } => Element::Heading { level: heading_level(level), id: String::new() },
+                    Tag::BlockQuote(_) => Element::BlockQuote,
+                    Tag::CodeBlock(CodeBlockKind::Fenced(info)) => Element::CodeBlock { info: info.to_string() },
+                    Tag::CodeBlock(CodeBlockKind::Indented) => Element::CodeBlock { info: String::new() },
+                    Tag::HtmlBlock => Element::HtmlBlock,
+                    Tag::List(start) => Element::List { start },
+                    Tag::Item => Element::Item,
+                    Tag::FootnoteDefinition(label) => Element::FootnoteDefinition(label.to_string()),
+                    Tag::Table(alignments) => Element::Table(alignments),
+                    Tag::TableHead => Element::TableHead,
+                    Tag::TableRow => Element::TableRow,
+                    Tag::TableCell => Element::TableCell,
+                    Tag::Emphasis => Element::Emphasis,
+                    Tag::Strong => Element::Strong,
+                    Tag::Strikethrough => Element::Strikethrough,
+                    Tag::Link { dest_url, title, .. } => Element::Link { url: dest_url.to_string(), title: title.to_string() },
+                    Tag::Image { dest_url, title, .. } => Element::Image { url: dest_url.to_string(), title: title.to_string() },
+                    _ => Element::Other,
+                };
+                stack.push((element, Vec::new()));
+                continue;
+            }
+            Event::End(_) => {
+                let (mut element, children) = stack.pop().expect("unbalanced markdown events");
+                match &mut element {
+                    Element::Heading { id, .. } => {
+                        let slug = slugify(&text_content(&children));
+                        let count = slugs.entry(slug.clone()).or_insert(0);
+                        *id = if *count == 0 { slug } else { format!("{}-{}", slug, count) };
+                        *count += 1;
+                    }
+                    Element::FootnoteDefinition(label) => {
+                        document.footnotes.push((label.clone(), children));
+                        continue;
+                    }
+                    _ => {}
+                }
+                Node::Element(element, children)
+            }
+            Event::Text(text) => Node::Text(text.to_string()),
+            Event::Code(code) => Node::Code(code.to_string()),
+            Event::Html(html) | Event::InlineHtml(html) => Node::Html(html.to_string()),
+            Event::FootnoteReference(label) => {
+                if !document.footnote_order.iter().any(|l| l == label.as_ref()) {
+                    document.footnote_order.push(label.to_string());
+                }
+                Node::FootnoteReference(label.to_string())
+            }
+            Event::TaskListMarker(checked) => Node::TaskListMarker(checked),
+            Event::SoftBreak => Node::SoftBreak,
+            Event::HardBreak => Node::HardBreak,
+            Event::Rule => Node::Rule,
+            Event::InlineMath(math) | Event::DisplayMath(math) => Node::Code(math.to_string()),
+        };
+        stack.last_mut().expect("markdown root").1.push(node);
+    }
+
+    document.blocks = stack.pop().map(|(_, blocks)| blocks).unwrap_or_default();
+    document
+}
+
+/// State shared while rendering one document.
+#[derive(Clone)]
+struct Context {
+    policy: MarkdownPolicy,
+    footnote_order: Arc<Vec<String>>,
+}
+
+impl Context {
+    fn footnote_number(&self, label: &str) -> Option<usize> {
+        self.footnote_order.iter().position(|l| l == label).map(|index| index + 1)
+    }
+}
+
+fn render_nodes(nodes: Vec<Node>, cx: &Context) -> AnyView {
+    if cx.policy == MarkdownPolicy::Untrusted || !nodes.iter().any(contains_inline_html) {
+        return nodes.into_iter().map(|node| render_node(node, cx)).collect_view().into_any();
+    }
+
+    // An inline tag and its closing tag arrive as separate events, so runs of
+    // inline content with raw HTML in them are rendered as one fragment.
+    let mut views = Vec::new();
+    let mut run = Vec::new();
+    for node in nodes {
+        if is_inline(&node) {
+            run.push(node);
+            continue;
+        }
+        views.push(render_run(std::mem::take(&mut run), cx));
+        views.push(render_node(node, cx));
+    }
+    views.push(render_run(run, cx));
+    views.into_iter().collect_view().into_any()
+}
+
+fn is_inline(node: &Node) -> bool {
+    match node {
+        Node::Element(element, _) => matches!(
+            element,
+            Element::Emphasis | Element::Strong | Element::Strikethrough | Element::Link { .. } | Element::Image { .. }
+        ),
+        Node::Rule => false,
+        _ => true,
+    }
+}
+
+fn contains_inline_html(node: &Node) -> bool {
+    match node {
+        Node::Html(_) => true,
+        Node::Element(_, children) => is_inline(node) && children.iter().any(contains_inline_html),
+        _ => false,
+    }
+}
+
+fn render_run(run: Vec<Node>, cx: &Context) -> AnyView {
+    if run.iter().any(contains_inline_html) {
+        let mut html = String::new();
+        inline_html(&run, cx, &mut html);
+        view! { <span class="contents" inner_html=html></span> }.into_any()
+    } else {
+        run.into_iter().map(|node| render_node(node, cx)).collect_view().into_any()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Inline `nodes` as an HTML string, with the markup `render_node` produces.
+fn inline_html(nodes: &[Node], cx: &Context, html: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => html.push_str(&escape(text)),
+            Node::Code(code) => html.push_str(&format!("<code class=\"{}\">{}</code>", INLINE_CODE_CLASS, escape(code))),
+            Node::Html(raw) => html.push_str(raw),
+            Node::FootnoteReference(label) => {
+                let slug = escape(&slugify(label));
+                let number = cx.footnote_number(label).unwrap_or_default();
+                html.push_str(&format!(
+                    "<sup id=\"fnref-{slug}\"><a href=\"#fn-{slug}\" class=\"text-accent hover:underline\">{number}</a></sup>"
+                ));
+            }
+            Node::TaskListMarker(checked) => html.push_str(&format!(
+                "<input type=\"checkbox\" class=\"mr-2 align-middle accent-accent\" disabled{}>",
+                if *checked { " checked" } else { "" }
+            )),
+            Node::SoftBreak => html.push(' '),
+            Node::HardBreak => html.push_str("<br>"),
+            Node::Rule => {}
+            Node::Element(element, children) => {
+                let (open, close) = match element {
+                    Element::Emphasis => ("<em>".to_string(), "</em>"),
+                    Element::Strong => ("<strong class=\"font-semibold\">".to_string(), "</strong>"),
+                    Element::Strikethrough => ("<del class=\"text-text-muted\">".to_string(), "</del>"),
+                    Element::Link { url, title } => match cx.policy.link_url(url) {
+                        Some(href) => {
+                            let title = if title.is_empty() { String::new() } else { format!(" title=\"{}\"", escape(title)) };
+                            let target = if is_external(href) { " target=\"_blank\" rel=\"noopener noreferrer\"" } else { "" };
+                            (
+                                format!(
+                                    "<a href=\"{}\" class=\"{}\"{}{}>",
+                                    escape(href),
+                                    LINK_CLASS,
+                                    title,
+                                    target
+                                ),
+                                "</a>",
+                            )
+                        }
+                        None => (String::new(), ""),
+                    },
+                    Element::Image { url, title } => {
+                        let alt = escape(&text_content(children));
+                        if let Some(src) = cx.policy.image_url(url) {
+                            let title = if title.is_empty() { String::new() } else { format!(" title=\"{}\"", escape(title)) };
+                            html.push_str(&format!(
+                                "<img src=\"{}\" alt=\"{}\"{} loading=\"lazy\" class=\"inline-block max-w-full rounded-md\">",
+                                escape(src),
+                                alt,
+                                title
+                            ));
+                        } else {
+                            html.push_str(&alt);
+                        }
+                        continue;
+                    }
+                    _ => (String::new(), ""),
+                };
+                html.push_str(&open);
+                inline_html(children, cx, html);
+                html.push_str(close);
+            }
+        }
+    }
+}
+
+fn render_node(node: Node, cx: &Context) -> AnyView {
+    match node {
+        Node::Text(text) => text.into_any(),
+        Node::Code(code) => view! { <InlineCode>{code}</InlineCode> }.into_any(),
+        Node::Html(html) => render_html(html, cx),
+        Node::FootnoteReference(label) => {
+            let slug = slugify(&label);
+            let number = cx.footnote_number(&label).unwrap_or_default();
+            view! {
+                <sup id=format!("fnref-{}", slug)>
+                    <a href=format!("#fn-{}", slug) class="text-accent hover:underline">{number}</a>
+                </sup>
+            }
+            .into_any()
+        }
+        Node::TaskListMarker(checked) => view! {
+            <input type="checkbox" class="mr-2 align-middle accent-accent" checked=checked disabled=true />
+        }
+        .into_any(),
+        Node::SoftBreak => " ".into_any(),
+        Node::HardBreak => view! { <br /> }.into_any(),
+        Node::Rule => view! { <hr class="my-8 border-border/50" /> }.into_any(),
+        Node::Element(element, children) => render_element(element, children, cx),
+    }
+}
+
+fn render_html(html: String, cx: &Context) -> AnyView {
+    if html.trim_start().starts_with("<!--") {
+        return ().into_any();
+    }
+    match cx.policy {
+        MarkdownPolicy::Trusted => view! { <span class="contents" inner_html=html></span> }.into_any(),
+        MarkdownPolicy::Untrusted => html.into_any(),
+    }
+}
+
+fn render_element(element: Element, children: Vec<Node>, cx: &Context) -> AnyView {
+    match element {
+        // Component children are closures, so content is rendered up front.
+        Element::Paragraph => {
+            let content = render_nodes(children, cx);
+            view! { <Body class="mb-4 leading-relaxed">{content}</Body> }.into_any()
+        }
+        Element::Heading { level, id } => {
+            let content = render_nodes(children, cx);
+            let class = "mt-8 mb-4 first:mt-0 scroll-mt-24";
+            if id.is_empty() {
+                view! { <Heading level=level class=class>{content}</Heading> }.into_any()
+            } else {
+                view! { <Heading level=level id=id class=class>{content}</Heading> }.into_any()
+            }
+        }
+        Element::BlockQuote => view! {
+            <blockquote class="mb-4 pl-4 border-l-4 border-accent text-text-secondary italic">
+                {render_nodes(children, cx)}
+            </blockquote>
+        }
+        .into_any(),
+        Element::CodeBlock { info } => {
+            let code = text_content(&children);
+            let (language, meta) = info.trim().split_once(char::is_whitespace).unwrap_or((info.trim(), ""));
+            if language == "mermaid" && cx.policy == MarkdownPolicy::Trusted {
+                view! { <MermaidDiagram source=code /> }.into_any()
+            } else {
+                view! {
+                    <CodeBlock
+                        code=code
+                        language=language.to_string()
+                        highlight=LineRanges::parse(meta)
+                        class="mb-4"
+                    />
+                }
+                .into_any()
+            }
+        }
+        Element::HtmlBlock => match cx.policy {
+            MarkdownPolicy::Trusted => {
+                let html: String = children
+                    .into_iter()
+                    .filter_map(|node| match node {
+                        Node::Html(html) => Some(html),
+                        _ => None,
+                    })
+                    .collect();
+                view! { <div class="mb-4" inner_html=html></div> }.into_any()
+            }
+            MarkdownPolicy::Untrusted => {
+                let content = render_nodes(children, cx);
+                view! { <Body class="mb-4 whitespace-pre-wrap">{content}</Body> }.into_any()
+            }
+        },
+        Element::List { start: Some(start) } => view! {
+            <ol start=start class="mb-4 pl-6 list-decimal space-y-1 font-inter">{render_nodes(children, cx)}</ol>
+        }
+        .into_any(),
+        Element::List { start: None } => view! {
+            <ul class="mb-4 pl-6 list-disc space-y-1 font-inter">{render_nodes(children, cx)}</ul>
+        }
+        .into_any(),
+        Element::Item => {
+            let task = matches!(children.first(), Some(Node::TaskListMarker(_)));
+            view! { <li class:list-none=task class:-ml-6=task>{render_nodes(children, cx)}</li> }.into_any()
+        }
+        Element::Table(alignments) => render_table(alignments, children, cx),
+        Element::Emphasis => view! { <em>{render_nodes(children, cx)}</em> }.into_any(),
+        Element::Strong => view! { <strong class="font-semibold">{render_nodes(children, cx)}</strong> }.into_any(),
+        Element::Strikethrough => view! { <del class="text-text-muted">{render_nodes(children, cx)}</del> }.into_any(),
+        Element::Link { url, title } => render_link(url, title, children, cx),
+        Element::Image { url, title } => {
+            let alt = text_content(&children);
+            match cx.policy.image_url(&url) {
+                Some(src) => view! {
+                    <img
+                        src=src.to_string()
+                        alt=alt
+                        title=(!title.is_empty()).then_some(title)
+                        loading="lazy"
+                        class="inline-block max-w-full rounded-md"
+                    />
+                }
+                .into_any(),
+                None => alt.into_any(),
+            }
+        }
+        // Table sections and cells are rendered by `render_table`; footnote
+        // definitions never reach the tree.
+        Element::TableHead | Element::TableRow | Element::TableCell | Element::FootnoteDefinition(_) | Element::Other => {
+            render_nodes(children, cx)
+        }
+    }
+}
+
+const LINK_CLASS: &str = "text-accent underline-offset-2 hover:underline hover:text-accent-hover";
+
+fn render_link(url: String, title: String, children: Vec<Node>, cx: &Context) -> AnyView {
+    let Some(href) = cx.policy.link_url(&url).map(str::to_string) else {
+        return render_nodes(children, cx);
+    };
+    let class = LINK_CLASS;
+    let title = (!title.is_empty()).then_some(title);
+    let content = render_nodes(children, cx);
+
+    if is_external(&href) {
+        let rel = match cx.policy {
+            MarkdownPolicy::Trusted => "noopener noreferrer",
+            MarkdownPolicy::Untrusted => "nofollow ugc noopener noreferrer",
+        };
+        view! { <a href=href class=class title=title target="_blank" rel=rel>{content}</a> }.into_any()
+    } else if href.starts_with('/') {
+        view! { <A href=href attr:class=class attr:title=title>{content}</A> }.into_any()
+    } else {
+        view! { <a href=href class=class title=title>{content}</a> }.into_any()
+    }
+}
+
+fn alignment_class(alignment: Option<&Alignment>) -> &'static str {
+    match alignment {
+        Some(Alignment::Center) => "text-center",
+        Some(Alignment::Right) => "text-right",
+        _ => "text-left",
+    }
+}
+
+fn render_cells(cells: Vec<Node>, header: bool, alignments: &[Alignment], cx: &Context) -> AnyView {
+    cells
+        .into_iter()
+        .enumerate()
+        .map(|(column, cell)| {
+            let content = match cell {
+                Node::Element(Element::TableCell, content) => content,
+                other => vec![other],
+            };
+            let align = alignment_class(alignments.get(column));
+            if header {
+                view! {
+                    <th scope="col" class=format!("px-3 py-2 font-outfit font-semibold border-b border-border {}", align)>
+                        {render_nodes(content, cx)}
+                    </th>
+                }
+                .into_any()
+            } else {
+                view! {
+                    <td class=format!("px-3 py-2 border-b border-border/50 {}", align)>{render_nodes(content, cx)}</td>
+                }
+                .into_any()
+            }
+        })
+        .collect_view()
+        .into_any()
+}
+
+fn render_table(alignments: Vec<Alignment>, children: Vec<Node>, cx: &Context) -> AnyView {
+    let mut head = Vec::new();
+    let mut rows = Vec::new();
+    for child in children {
+        match child {
+            Node::Element(Element::TableHead, cells) => head = cells,
+            Node::Element(Element::TableRow, cells) => rows.push(cells),
+            _ => {}
+        }
+    }
+
+    view! {
+        <div class="mb-4 overflow-x-auto">
+            <table class="min-w-full text-sm font-inter border-collapse">
+                <thead>
+                    <tr>{render_cells(head, true, &alignments, cx)}</tr>
+                </thead>
+                <tbody>
+                    {rows
+                        .into_iter()
+                        .map(|cells| view! { <tr class="even:bg-surface/50">{render_cells(cells, false, &alignments, cx)}</tr> })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+    .into_any()
+}
+
+fn render_footnotes(mut footnotes: Vec<(String, Vec<Node>)>, cx: &Context) -> Option<AnyView> {
+    if footnotes.is_empty() {
+        return None;
+    }
+    // Referenced notes in reference order, then any unreferenced ones.
+    footnotes.sort_by_key(|(label, _)| cx.footnote_number(label).unwrap_or(usize::MAX));
+
+    Some(
+        view! {
+            <section class="mt-8 pt-4 border-t border-border/50 text-sm" aria-label="Footnotes">
+                <ol class="pl-6 list-decimal space-y-2 font-inter text-text-secondary">
+                    {footnotes
+                        .into_iter()
+                        .map(|(label, content)| {
+                            let slug = slugify(&label);
+                            view! {
+                                <li id=format!("fn-{}", slug) class="[&_p]:inline [&_p]:mb-0">
+                                    {render_nodes(content, cx)}
+                                    " "
+                                    <a href=format!("#fnref-{}", slug) class="text-accent" aria-label="Back to reference">
+                                        "↩"
+                                    </a>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ol>
+            </section>
+        }
+        .into_any(),
+    )
+}
+
+fn render_document(source: &str, policy: MarkdownPolicy) -> AnyView {
+    let Document { blocks, footnotes, footnote_order } = parse(source);
+    let cx = Context { policy, footnote_order: Arc::new(footnote_order) };
+
+    view! {
+        {render_nodes(blocks, &cx)}
+        {render_footnotes(footnotes, &cx)}
+    }
+    .into_any()
+}
+
+/// Renders Markdown `source` with design-system typography. `source` is a
+/// signal so content fetched at runtime re-renders when it arrives; see
+/// [`MarkdownPolicy`] for what untrusted input may do.
+#[component]
+pub fn Markdown(
+    #[prop(into)] source: Signal<String>,
+    #[prop(optional)] policy: MarkdownPolicy,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    view! {
+        <div class=format!("text-text-primary {}", class.unwrap_or_default())>
+            {move || source.with(|source| render_document(source, policy))}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNTRUSTED: MarkdownPolicy = MarkdownPolicy::Untrusted;
+    const TRUSTED: MarkdownPolicy = MarkdownPolicy::Trusted;
+
+    #[test]
+    fn script_and_data_urls_are_refused() {
+        for url in ["javascript:alert(1)", "JavaScript:alert(1)", " javascript:x", "java\tscript:x", "java\nscript:x", "vbscript:x", "data:text/html,<script>"] {
+            assert_eq!(UNTRUSTED.link_url(url), None, "{url:?}");
+            assert_eq!(TRUSTED.link_url(url), None, "{url:?}");
+            assert_eq!(TRUSTED.image_url(url), None, "{url:?}");
+        }
+        assert_eq!(TRUSTED.image_url("data:image/png;base64,AAAA"), None);
+    }
+
+    #[test]
+    fn links_allow_web_mail_and_relative_urls() {
+        for url in ["https://example.com", "http://example.com", "mailto:ada@example.com", "/docs", "docs/a.md", "#intro", "?q=1"] {
+            assert_eq!(UNTRUSTED.link_url(url), Some(url), "{url:?}");
+        }
+        assert_eq!(UNTRUSTED.link_url("tel:+15550100"), None);
+        assert_eq!(TRUSTED.link_url("tel:+15550100"), Some("tel:+15550100"));
+    }
+
+    #[test]
+    fn untrusted_images_stay_on_site() {
+        assert_eq!(UNTRUSTED.image_url("/assets/a.png"), Some("/assets/a.png"));
+        assert_eq!(UNTRUSTED.image_url("a.png"), Some("a.png"));
+        for url in ["https://evil.com/x.png", "//evil.com/x.png", "/\\evil.com/x.png", "\\\\evil.com/x.png", "/\t/evil.com/x.png"] {
+            assert_eq!(UNTRUSTED.image_url(url), None, "{url:?}");
+        }
+        assert_eq!(TRUSTED.image_url("https://example.com/x.png"), Some("https://example.com/x.png"));
+        assert_eq!(TRUSTED.image_url("//example.com/x.png"), None);
+    }
+
+    #[test]
+    fn network_paths_are_external() {
+        for url in ["//evil.com", "/\\evil.com", "\\\\evil.com", "/\n/evil.com", "https://example.com"] {
+            assert!(is_external(url), "{url:?}");
+        }
+        for url in ["/docs", "/docs/a\\b", "docs", "#fn-1", "mailto:ada@example.com"] {
+            assert!(!is_external(url), "{url:?}");
+        }
+    }
+
+    #[test]
+    fn raw_html_is_kept_apart_from_text() {
+        let document = parse("Hi <b>there</b>\n\n<script>alert(1)</script>\n");
+        let Node::Element(Element::Paragraph, inline) = &document.blocks[0] else { panic!("expected a paragraph") };
+        assert!(inline.iter().any(|node| matches!(node, Node::Html(html) if html == "<b>")));
+        let Node::Element(Element::HtmlBlock, block) = &document.blocks[1] else { panic!("expected an HTML block") };
+        assert!(block.iter().all(|node| matches!(node, Node::Html(_))));
+    }
+
+    #[test]
+    fn inline_html_escapes_text_and_filters_urls() {
+        let cx = Context { policy: TRUSTED, footnote_order: Arc::new(Vec::new()) };
+        let document = parse("<i>a</i> 1 < 2 [x](javascript:alert(1)) [y](//evil.com) ![z](data:image/png,AA)");
+        let Node::Element(Element::Paragraph, inline) = &document.blocks[0] else { panic!("expected a paragraph") };
+        let mut html = String::new();
+        inline_html(inline, &cx, &mut html);
+        assert!(html.contains("<i>a</i> 1 &lt; 2 x"), "{html}");
+        assert!(!html.contains("javascript:"), "{html}");
+        assert!(!html.contains("data:"), "{html}");
+        assert!(html.contains("href=\"//evil.com\" class=\"") && html.contains("target=\"_blank\""), "{html}");
+    }
+}
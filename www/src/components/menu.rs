@@ -0,0 +1,619 @@
+//! `DropdownMenu`: a menu button with items, checkbox and radio items,
+//! separators and nested `SubMenu`s.
+//!
+//! Follows the WAI-ARIA menu button pattern. The trigger opens the menu with
+//! Enter, Space or the arrow keys; inside, Up/Down/Home/End move between
+//! items, typing jumps to the next item starting with the typed text,
+//! Right/Left open and close submenus, and Escape closes one level. Menus and
+//! submenus are portaled and anchored with `position::use_position`, flipping
+//! to stay on screen.
+//!
+//! Focus follows the pointer, so the focused item is the highlighted one.
+//! Plain items close the whole menu when chosen; checkbox and radio items keep
+//! it open so several options can be set in a row.
+
+use std::time::Duration;
+
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+use crate::components::ids::next_id;
+use crate::components::position::{use_position, Placement, Position, PositionOptions};
+use crate::components::roving::{self, Orientation};
+
+/// How long typed characters accumulate into one typeahead search.
+const TYPEAHEAD_RESET: Duration = Duration::from_millis(500);
+
+const PANEL_CLASS: &str = "fixed z-[70] min-w-[12rem] max-w-xs p-1 rounded-lg bg-surface-elevated border border-border shadow-[0_0_12px_2px_theme(colors.glow)] focus:outline-none";
+const ITEM_CLASS: &str = "flex w-full items-center gap-2 px-3 py-1.5 rounded text-left text-sm font-inter text-text-primary cursor-pointer select-none focus:bg-base02 focus:outline-none";
+
+/// Which item to focus when a menu opens.
+#[derive(Clone, Copy, PartialEq)]
+enum Edge {
+    First,
+    Last,
+}
+
+/// The items of `menu` itself, excluding those of its submenus.
+fn items(menu: &web_sys::Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = menu.query_selector_all("[data-menu-item]") else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|item| item.closest("[role=menu]").ok().flatten().as_ref() == Some(menu))
+        .collect()
+}
+
+fn focus_edge(menu: &web_sys::Element, edge: Edge) {
+    let items = items(menu);
+    let item = match edge {
+        Edge::First => items.first(),
+        Edge::Last => items.last(),
+    };
+    if let Some(item) = item {
+        let _ = item.focus();
+    }
+}
+
+/// Shared by every menu level of one `DropdownMenu`.
+#[derive(Clone, Copy)]
+struct MenuRoot {
+    id: usize,
+    open: RwSignal<bool>,
+    trigger: NodeRef<html::Button>,
+}
+
+impl MenuRoot {
+    fn close(&self, refocus: bool) {
+        self.open.set(false);
+        if refocus {
+            if let Some(trigger) = self.trigger.get_untracked() {
+                let _ = trigger.focus();
+            }
+        }
+    }
+}
+
+/// One menu panel: the root menu or a submenu.
+#[derive(Clone, Copy)]
+struct MenuLevel {
+    menu: NodeRef<html::Div>,
+    /// The submenu open under this level.
+    open_sub: RwSignal<Option<usize>>,
+    /// For submenus: the parent level's `open_sub` and the item opening this
+    /// submenu.
+    parent: Option<(RwSignal<Option<usize>>, NodeRef<html::Div>)>,
+}
+
+impl MenuLevel {
+    /// Close this submenu and focus the item that opened it.
+    fn close_sub(&self) -> bool {
+        let Some((parent_open, item)) = self.parent else {
+            return false;
+        };
+        parent_open.set(None);
+        if let Some(item) = item.get_untracked() {
+            let _ = item.focus();
+        }
+        true
+    }
+}
+
+/// The panel markup and keyboard handling shared by menus and submenus.
+#[component]
+fn MenuPanel(
+    id: String,
+    labelled_by: String,
+    level: MenuLevel,
+    position: ReadSignal<Option<Position>>,
+    class: String,
+    children: Children,
+) -> impl IntoView {
+    let root = expect_context::<MenuRoot>();
+    let typed = StoredValue::new(String::new());
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+    on_cleanup(move || {
+        if let Some(Some(handle)) = timer.try_update_value(Option::take) {
+            handle.clear();
+        }
+    });
+
+    let typeahead = move |items: &[HtmlElement], current: Option<usize>, key: &str| {
+        if let Some(Some(handle)) = timer.try_update_value(Option::take) {
+            handle.clear();
+        }
+        let query = typed.with_value(|typed| format!("{}{}", typed, key.to_lowercase()));
+        // Repeating one letter cycles through the items starting with it.
+        let query = if query.chars().all(|c| query.starts_with(c)) {
+            query.chars().take(1).collect()
+        } else {
+            query
+        };
+        let start = match current {
+            Some(current) if query.chars().count() == 1 => current + 1,
+            Some(current) => current,
+            None => 0,
+        };
+        let count = items.len();
+        let found = (0..count).map(|offset| (start + offset) % count).find(|&index| {
+            items[index].text_content().is_some_and(|text| text.trim().to_lowercase().starts_with(&query))
+        });
+        if let Some(index) = found {
+            let _ = items[index].focus();
+        }
+        typed.set_value(query);
+        let handle = set_timeout_with_handle(
+            move || {
+                typed.try_update_value(String::clear);
+            },
+            TYPEAHEAD_RESET,
+        );
+        timer.set_value(handle.ok());
+    };
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let Some(menu) = level.menu.get_untracked() else {
+            return;
+        };
+        let menu: web_sys::Element = menu.into();
+        let items = items(&menu);
+        let active = document().active_element();
+        let current = items.iter().position(|item| Some(item.unchecked_ref::<web_sys::Element>()) == active.as_ref());
+        let key = ev.key();
+        match key.as_str() {
+            "ArrowDown" | "ArrowUp" | "Home" | "End" => {
+                ev.prevent_default();
+                level.open_sub.set(None);
+                let next = match current {
+                    Some(current) => roving::next_index(&key, current, items.len(), Orientation::Vertical),
+                    None if key == "ArrowUp" || key == "End" => items.len().checked_sub(1),
+                    None => (!items.is_empty()).then_some(0),
+                };
+                if let Some(item) = next.and_then(|next| items.get(next)) {
+                    let _ = item.focus();
+                }
+            }
+            "Escape" => {
+                ev.prevent_default();
+                ev.stop_propagation();
+                if !level.close_sub() {
+                    root.close(true);
+                }
+            }
+            "ArrowLeft" => {
+                if level.close_sub() {
+                    ev.prevent_default();
+                }
+            }
+            "Tab" => {
+                ev.prevent_default();
+                root.close(true);
+            }
+            _ if key.chars().count() == 1 && key != " " && !ev.ctrl_key() && !ev.meta_key() && !ev.alt_key() => {
+                ev.prevent_default();
+                ev.stop_propagation();
+                typeahead(&items, current, &key);
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <div
+            node_ref=level.menu
+            id=id
+            role="menu"
+            aria-orientation="vertical"
+            aria-labelledby=labelled_by
+            tabindex="-1"
+            data-menu=root.id.to_string()
+            class=format!("{} {}", PANEL_CLASS, class)
+            class:invisible=move || position.get().is_none()
+            style=move || position.get().map(|position| position.style()).unwrap_or_default()
+            on:keydown=on_keydown
+        >
+            <Provider value=level>{children()}</Provider>
+        </div>
+    }
+}
+
+/// A button that opens a menu of actions and options.
+///
+/// `trigger` is rendered inside the menu button. Children are `MenuItem`,
+/// `MenuCheckboxItem`, `MenuRadioGroup`, `MenuSeparator` and `SubMenu`. Pass
+/// `open` to control the menu from outside.
+#[component]
+pub fn DropdownMenu(
+    #[prop(into)] trigger: ViewFn,
+    #[prop(optional)] open: Option<RwSignal<bool>>,
+    #[prop(optional)] placement: Option<Placement>,
+    #[prop(into, optional)] trigger_class: Option<String>,
+    #[prop(optional, into)] class: String,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let id = next_id();
+    let trigger_id = format!("menu-{}-trigger", id);
+    let menu_id = format!("menu-{}", id);
+    let open = open.unwrap_or_else(|| RwSignal::new(false));
+    let trigger_ref = NodeRef::<html::Button>::new();
+    let root = MenuRoot { id, open, trigger: trigger_ref };
+
+    let level = MenuLevel { menu: NodeRef::new(), open_sub: RwSignal::new(None), parent: None };
+    let options = PositionOptions::new(placement.unwrap_or(Placement::BOTTOM_START)).offset(4.0);
+    let position = use_position(move || trigger_ref.get_untracked().map(Into::into), level.menu, open.into(), options);
+
+    // Focus moves in once the menu has been positioned, so the page doesn't
+    // scroll to where it was first laid out.
+    let initial = StoredValue::new(Edge::First);
+    Effect::new(move |focused: Option<bool>| {
+        let placed = open.get() && position.get().is_some();
+        if placed && !focused.unwrap_or(false) {
+            if let Some(menu) = level.menu.get_untracked() {
+                focus_edge(&menu, initial.get_value());
+            }
+        }
+        if !open.get() {
+            level.open_sub.set(None);
+        }
+        placed
+    });
+
+    let outside_click = StoredValue::new_local(None::<WindowListenerHandle>);
+    Effect::new(move |_| {
+        if open.get() {
+            let handle = window_event_listener(ev::pointerdown, move |ev| {
+                let target = ev.target().and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+                let inside = target.is_some_and(|target| {
+                    matches!(target.closest(&format!("[data-menu=\"{}\"]", id)), Ok(Some(_)))
+                        || trigger_ref.get_untracked().is_some_and(|trigger| trigger.contains(Some(&target)))
+                });
+                if !inside {
+                    root.close(false);
+                }
+            });
+            outside_click.set_value(Some(handle));
+        } else if let Some(handle) = outside_click.try_update_value(Option::take).flatten() {
+            handle.remove();
+        }
+    });
+    on_cleanup(move || {
+        if let Some(Some(handle)) = outside_click.try_update_value(Option::take) {
+            handle.remove();
+        }
+    });
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let edge = match ev.key().as_str() {
+            "ArrowDown" => Edge::First,
+            "ArrowUp" => Edge::Last,
+            _ => return,
+        };
+        ev.prevent_default();
+        initial.set_value(edge);
+        if open.get_untracked() {
+            if let Some(menu) = level.menu.get_untracked() {
+                focus_edge(&menu, edge);
+            }
+        } else {
+            open.set(true);
+        }
+    };
+
+    let panel_id = StoredValue::new(menu_id.clone());
+    let labelled_by = StoredValue::new(trigger_id.clone());
+    let class = StoredValue::new(class);
+    let children = StoredValue::new(children);
+
+    // Scoped so sibling menus, rendered lazily below, don't see each other's.
+    view! {
+        <Provider value=root>
+            <button
+                node_ref=trigger_ref
+                id=trigger_id
+                type="button"
+                class=trigger_class.unwrap_or_else(|| "btn-secondary inline-flex items-center gap-2".to_string())
+                aria-haspopup="menu"
+                aria-expanded=move || open.get().to_string()
+                aria-controls=move || open.get().then(|| menu_id.clone())
+                on:click=move |_| {
+                    initial.set_value(Edge::First);
+                    open.update(|open| *open = !*open);
+                }
+                on:keydown=on_keydown
+            >
+                {trigger.run()}
+            </button>
+            <Show when=move || open.get()>
+                <Portal>
+                    <MenuPanel
+                        id=panel_id.get_value()
+                        labelled_by=labelled_by.get_value()
+                        level=level
+                        position=position
+                        class=class.get_value()
+                    >
+                        {children.with_value(|children| children())}
+                    </MenuPanel>
+                </Portal>
+            </Show>
+        </Provider>
+    }
+}
+
+/// Focus an item under the pointer and close any sibling submenu.
+fn hover(ev: &ev::PointerEvent, level: MenuLevel, opens: Option<usize>) {
+    if let Some(item) = ev.current_target().and_then(|target| target.dyn_into::<HtmlElement>().ok()) {
+        let _ = item.focus();
+    }
+    if level.open_sub.get_untracked() != opens {
+        level.open_sub.set(None);
+    }
+}
+
+/// Whether a keydown activates the focused item.
+fn is_activation(ev: &ev::KeyboardEvent) -> bool {
+    matches!(ev.key().as_str(), "Enter" | " ")
+}
+
+/// An action. Choosing it runs `on_select` and closes the menu.
+#[component]
+pub fn MenuItem(
+    #[prop(into)] on_select: Callback<()>,
+    #[prop(optional)] disabled: bool,
+    /// Keyboard shortcut shown at the end of the item.
+    #[prop(optional, into)]
+    shortcut: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let root = expect_context::<MenuRoot>();
+    let level = expect_context::<MenuLevel>();
+    let select = move || {
+        if !disabled {
+            root.close(true);
+            on_select.run(());
+        }
+    };
+
+    view! {
+        <div
+            role="menuitem"
+            tabindex="-1"
+            data-menu-item=""
+            aria-disabled=disabled.then_some("true")
+            class=ITEM_CLASS
+            class=("opacity-50", disabled)
+            class=("cursor-not-allowed", disabled)
+            on:click=move |_| select()
+            on:keydown=move |ev| {
+                if is_activation(&ev) {
+                    ev.prevent_default();
+                    select();
+                }
+            }
+            on:pointerenter=move |ev| hover(&ev, level, None)
+        >
+            <span class="flex-1">{children()}</span>
+            {shortcut.map(|shortcut| view! { <kbd class="ml-4 text-xs font-mono text-text-muted">{shortcut}</kbd> })}
+        </div>
+    }
+}
+
+/// The indicator column of checkbox and radio items.
+#[component]
+fn Indicator(checked: Signal<bool>, mark: &'static str) -> impl IntoView {
+    view! {
+        <span class="w-4 shrink-0 text-center text-accent" aria-hidden="true">
+            {move || checked.get().then_some(mark)}
+        </span>
+    }
+}
+
+/// An option that is toggled on or off. The menu stays open.
+#[component]
+pub fn MenuCheckboxItem(
+    checked: RwSignal<bool>,
+    #[prop(optional)] disabled: bool,
+    children: Children,
+) -> impl IntoView {
+    let level = expect_context::<MenuLevel>();
+    let toggle = move || {
+        if !disabled {
+            checked.update(|checked| *checked = !*checked);
+        }
+    };
+
+    view! {
+        <div
+            role="menuitemcheckbox"
+            tabindex="-1"
+            data-menu-item=""
+            aria-checked=move || checked.get().to_string()
+            aria-disabled=disabled.then_some("true")
+            class=ITEM_CLASS
+            class=("opacity-50", disabled)
+            class=("cursor-not-allowed", disabled)
+            on:click=move |_| toggle()
+            on:keydown=move |ev| {
+                if is_activation(&ev) {
+                    ev.prevent_default();
+                    toggle();
+                }
+            }
+            on:pointerenter=move |ev| hover(&ev, level, None)
+        >
+            <Indicator checked=checked.into() mark="✓" />
+            <span class="flex-1">{children()}</span>
+        </div>
+    }
+}
+
+#[derive(Clone, Copy)]
+struct RadioContext {
+    value: RwSignal<String>,
+}
+
+/// A set of `MenuRadioItem`s of which one is selected, holding the selected
+/// item's value.
+#[component]
+pub fn MenuRadioGroup(
+    value: RwSignal<String>,
+    /// Accessible name for the group.
+    #[prop(optional, into)]
+    label: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div role="group" aria-label=label>
+            <Provider value=RadioContext { value }>{children()}</Provider>
+        </div>
+    }
+}
+
+/// An option of a `MenuRadioGroup`. The menu stays open.
+#[component]
+pub fn MenuRadioItem(
+    #[prop(into)] value: String,
+    #[prop(optional)] disabled: bool,
+    children: Children,
+) -> impl IntoView {
+    let level = expect_context::<MenuLevel>();
+    let group = expect_context::<RadioContext>();
+    let value = StoredValue::new(value);
+    let checked = Signal::derive(move || value.with_value(|value| group.value.with(|selected| selected == value)));
+    let select = move || {
+        if !disabled {
+            group.value.set(value.get_value());
+        }
+    };
+
+    view! {
+        <div
+            role="menuitemradio"
+            tabindex="-1"
+            data-menu-item=""
+            aria-checked=move || checked.get().to_string()
+            aria-disabled=disabled.then_some("true")
+            class=ITEM_CLASS
+            class=("opacity-50", disabled)
+            class=("cursor-not-allowed", disabled)
+            on:click=move |_| select()
+            on:keydown=move |ev| {
+                if is_activation(&ev) {
+                    ev.prevent_default();
+                    select();
+                }
+            }
+            on:pointerenter=move |ev| hover(&ev, level, None)
+        >
+            <Indicator checked=checked mark="●" />
+            <span class="flex-1">{children()}</span>
+        </div>
+    }
+}
+
+#[component]
+pub fn MenuSeparator() -> impl IntoView {
+    view! { <div role="separator" class="my-1 h-px bg-border"></div> }
+}
+
+/// An item opening a nested menu, beside it.
+///
+/// The submenu opens on hover, click, Enter, Space or Right, and closes with
+/// Left or Escape, returning focus to this item.
+#[component]
+pub fn SubMenu(#[prop(into)] label: String, #[prop(optional)] disabled: bool, children: ChildrenFn) -> impl IntoView {
+    let id = next_id();
+    let item_id = format!("menu-{}-trigger", id);
+    let menu_id = format!("menu-{}", id);
+    let parent = expect_context::<MenuLevel>();
+    let item = NodeRef::<html::Div>::new();
+    let level = MenuLevel { menu: NodeRef::new(), open_sub: RwSignal::new(None), parent: Some((parent.open_sub, item)) };
+    let open = Signal::derive(move || parent.open_sub.get() == Some(id));
+
+    let options = PositionOptions::new(Placement::RIGHT_START).offset(4.0);
+    let position = use_position(move || item.get_untracked().map(Into::into), level.menu, open, options);
+
+    let focus_on_open = StoredValue::new(false);
+    Effect::new(move |focused: Option<bool>| {
+        let placed = open.get() && position.get().is_some();
+        if placed && !focused.unwrap_or(false) && focus_on_open.get_value() {
+            if let Some(menu) = level.menu.get_untracked() {
+                focus_edge(&menu, Edge::First);
+            }
+        }
+        if !open.get() {
+            level.open_sub.set(None);
+        }
+        placed
+    });
+
+    let show = move |focus: bool| {
+        if disabled {
+            return;
+        }
+        focus_on_open.set_value(focus);
+        if open.get_untracked() {
+            if let (true, Some(menu)) = (focus, level.menu.get_untracked()) {
+                focus_edge(&menu, Edge::First);
+            }
+        } else {
+            parent.open_sub.set(Some(id));
+        }
+    };
+
+    let panel_id = StoredValue::new(menu_id.clone());
+    let labelled_by = StoredValue::new(item_id.clone());
+    let children = StoredValue::new(children);
+
+    view! {
+        <div
+            node_ref=item
+            id=item_id
+            role="menuitem"
+            tabindex="-1"
+            data-menu-item=""
+            aria-haspopup="menu"
+            aria-expanded=move || open.get().to_string()
+            aria-controls=move || open.get().then(|| menu_id.clone())
+            aria-disabled=disabled.then_some("true")
+            class=ITEM_CLASS
+            class=("bg-base02", move || open.get())
+            class=("opacity-50", disabled)
+            class=("cursor-not-allowed", disabled)
+            on:click=move |_| show(true)
+            on:keydown=move |ev| {
+                if is_activation(&ev) || ev.key() == "ArrowRight" {
+                    ev.prevent_default();
+                    show(true);
+                }
+            }
+            on:pointerenter=move |ev| {
+                hover(&ev, parent, Some(id));
+                show(false);
+            }
+        >
+            <span class="flex-1">{label}</span>
+            <span class="text-text-muted" aria-hidden="true">"▸"</span>
+        </div>
+        <Show when=move || open.get()>
+            <Portal>
+                <MenuPanel
+                    id=panel_id.get_value()
+                    labelled_by=labelled_by.get_value()
+                    level=level
+                    position=position
+                    class=String::new()
+                >
+                    {children.with_value(|children| children())}
+                </MenuPanel>
+            </Portal>
+        </Show>
+    }
+}
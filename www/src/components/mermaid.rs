@@ -0,0 +1,118 @@
+//! Mermaid diagrams rendered in the browser.
+//!
+//! The mermaid ESM bundle is large, so it is imported on first use rather
+//! than shipped with the app, and shared by every diagram on the page. It runs
+//! with `securityLevel: "strict"`, which sanitizes labels and disables click
+//! handlers.
+//!
+//! The bundle comes from jsDelivr, not from our own build: it splits into
+//! dozens of lazily imported ESM files, which Trunk can't vendor as one
+//! asset. `import()` can't carry an `integrity` hash, so what we rely on is:
+//! - the URL names an exact npm version, and jsDelivr serves published npm
+//!   files immutably;
+//! - only `MarkdownPolicy::Trusted` content, which we author, renders
+//!   diagrams, so visitors' content never makes the page contact the CDN;
+//! - deployments that set a CSP list `https://cdn.jsdelivr.net` in
+//!   `script-src` explicitly.
+//!
+//! Bumping `MERMAID_URL` is a reviewed change like any dependency update.
+
+use std::cell::RefCell;
+
+use js_sys::{Function, Object, Promise, Reflect};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+use crate::chunks::import_module;
+use crate::components::code_block::CodeBlock;
+use crate::components::ids::unique_id;
+use crate::components::theme::{Theme, ThemeContext};
+
+const MERMAID_URL: &str = "https://cdn.jsdelivr.net/npm/mermaid@11.4.1/dist/mermaid.esm.min.mjs";
+
+thread_local! {
+    /// The pending or settled `import()` of the mermaid bundle.
+    static MERMAID: RefCell<Option<Promise>> = const { RefCell::new(None) };
+}
+
+async fn load_mermaid() -> Result<JsValue, JsValue> {
+    let import = MERMAID.with(|mermaid| mermaid.borrow_mut().get_or_insert_with(|| import_module(MERMAID_URL)).clone());
+    let module = JsFuture::from(import).await?;
+    Reflect::get(&module, &JsValue::from_str("default"))
+}
+
+fn call(target: &JsValue, method: &str, args: &[&JsValue]) -> Result<JsValue, JsValue> {
+    let function = Reflect::get(target, &JsValue::from_str(method))?.dyn_into::<Function>()?;
+    match args {
+        [] => function.call0(target),
+        [a] => function.call1(target, a),
+        [a, b] => function.call2(target, a, b),
+        _ => Err(JsValue::from_str("too many arguments")),
+    }
+}
+
+/// Render `source` to an SVG string.
+async fn render_svg(id: &str, source: &str, dark: bool) -> Result<String, JsValue> {
+    let mermaid = load_mermaid().await?;
+
+    let config = Object::new();
+    Reflect::set(&config, &"startOnLoad".into(), &JsValue::FALSE)?;
+    Reflect::set(&config, &"securityLevel".into(), &"strict".into())?;
+    Reflect::set(&config, &"theme".into(), &(if dark { "dark" } else { "neutral" }).into())?;
+    call(&mermaid, "initialize", &[&config])?;
+
+    let rendered = call(&mermaid, "render", &[&id.into(), &source.into()])?;
+    let result = JsFuture::from(Promise::from(rendered)).await?;
+    Reflect::get(&result, &"svg".into())?
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("mermaid returned no svg"))
+}
+
+/// A mermaid diagram. Re-renders when the theme changes; if mermaid fails
+/// to load or the source doesn't parse, the source is shown as code.
+#[component]
+pub fn MermaidDiagram(#[prop(into)] source: String, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    let id = unique_id("mermaid");
+    let svg = RwSignal::new(None::<Result<String, String>>);
+    let theme = use_context::<ThemeContext>().map(|ctx| ctx.theme);
+    let source = StoredValue::new(source);
+
+    Effect::new(move |_| {
+        let dark = theme.is_some_and(|theme| matches!(theme.get(), Theme::Dark | Theme::HighContrast));
+        let id = id.clone();
+        spawn_local(async move {
+            let result = render_svg(&id, &source.get_value(), dark).await.map_err(|err| {
+                log::warn!("mermaid render failed: {:?}", err);
+                err.as_string().unwrap_or_else(|| "Diagram could not be rendered".to_string())
+            });
+            svg.try_set(Some(result));
+        });
+    });
+
+    view! {
+        <figure class=format!("mb-4 {}", class.unwrap_or_default())>
+            {move || match svg.get() {
+                None => view! {
+                    <div
+                        class="p-4 rounded-md bg-surface-elevated text-sm font-inter text-text-muted animate-pulse motion-reduce:animate-none"
+                        aria-busy="true"
+                    >
+                        "Rendering diagram…"
+                    </div>
+                }
+                .into_any(),
+                Some(Ok(svg)) => view! {
+                    <div class="flex justify-center overflow-x-auto p-4 rounded-md bg-surface-elevated" inner_html=svg></div>
+                }
+                .into_any(),
+                Some(Err(message)) => view! {
+                    <CodeBlock code=source.get_value() language="mermaid" />
+                    <figcaption class="mt-1 text-xs font-inter text-error">{message}</figcaption>
+                }
+                .into_any(),
+            }}
+        </figure>
+    }
+}
@@ -2,20 +2,72 @@
 pub mod theme;
 pub mod typography;
 pub mod button;
+pub mod button_group;
 pub mod card;
+pub mod code_block;
+pub mod command_palette;
 pub mod counter_btn;
+pub mod data_table;
+pub mod dialog;
+pub mod error_boundary;
+pub mod disclosure;
+pub mod focus;
+pub mod footer;
+pub mod form;
+pub mod hash;
+pub mod highlight;
+pub mod icon;
+pub mod ids;
+pub mod image;
+pub mod inputs;
 pub mod layout;
+pub mod loading;
+pub mod markdown;
+pub mod menu;
+pub mod mermaid;
 pub mod navigation;
+pub mod popover;
+pub mod position;
+pub mod roving;
+pub mod storybook;
+pub mod tabs;
+pub mod toast;
+pub mod tooltip;
+pub mod validation;
 
 // Re-exports for easier imports
 pub use theme::{Theme, ThemeContext, ThemeProvider, ThemeToggle};
 pub use typography::{
+    Heading, HeadingLevel, Text, TextSize, TextTag, TextTone, TextWeight,
     Heading1, Heading2, Heading3, Heading4, Heading5,
     BodyLarge, Body, BodySmall, Caption,
-    CodeBlock, InlineCode,
+    InlineCode,
 };
-pub use button::{Button, ButtonVariant};
-pub use card::{Card, CardVariant};
+pub use button::{Button, ButtonSize, ButtonType, ButtonVariant};
+pub use button_group::{ButtonGroup, SelectionMode, SplitAction, SplitButton, ToggleButton, ToggleOption};
+pub use card::{Card, CardBody, CardFooter, CardHeader, CardMedia, CardVariant};
+pub use code_block::CodeBlock;
+pub use command_palette::{use_commands, Command, CommandAction, CommandGroup, CommandPaletteButton, CommandProvider, CommandRegistry};
 pub use counter_btn::CounterButton;
+pub use data_table::{CellValue, Column, DataTable, SortDirection};
+pub use dialog::{Dialog, DialogSize};
+pub use error_boundary::{ErrorBoundary, ErrorCard};
+pub use disclosure::{Accordion, AccordionItem, Disclosure};
+pub use footer::{Footer, FooterColumn, FooterLink, NewsletterSignup, SocialLink};
+pub use form::{Field, Form, FormState, SubmitError, SubmitStatus};
+pub use icon::{Icon, IconName, IconSize};
+pub use image::{Image, ImageAsset, Placeholder};
+pub use inputs::{Checkbox, RadioGroup, Select, SelectOption, Slider, Switch, TextArea, TextInput};
 pub use layout::MainLayout;
+pub use loading::{Progress, ProgressRing, Skeleton, SkeletonShape, Spinner, SpinnerSize};
+pub use markdown::{Markdown, MarkdownPolicy};
+pub use menu::{DropdownMenu, MenuCheckboxItem, MenuItem, MenuRadioGroup, MenuRadioItem, MenuSeparator, SubMenu};
+pub use mermaid::MermaidDiagram;
 pub use navigation::Navigation;
+pub use popover::Popover;
+pub use position::{Align, Placement, PositionOptions, Side};
+pub use storybook::{Args, Story, StoryBook, StoryEnum, StoryGallery, StoryRegistry};
+pub use tabs::{Activation, Tab, TabList, TabPanel, Tabs};
+pub use toast::{use_toast, ToastAction, ToastId, ToastKind, ToastOptions, ToastPosition, ToastProvider, Toasts};
+pub use tooltip::Tooltip;
+pub use validation::{AsyncValidator, Validator};
@@ -0,0 +1,155 @@
+use leptos::ev;
+use leptos::html;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::components::focus;
+use crate::components::ids::unique_id;
+use crate::components::position::{use_position, Placement, PositionOptions};
+
+const ARROW: f64 = 10.0;
+
+/// Whether `ev`'s target is inside `el`.
+fn event_within(ev: &ev::Event, el: Option<impl AsRef<web_sys::Node>>) -> bool {
+    let target = ev.target().and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+    matches!((el, target), (Some(el), Some(target)) if el.as_ref().contains(Some(&target)))
+}
+
+/// Click-triggered panel with interactive content, anchored to its trigger.
+///
+/// `trigger` is rendered inside a `<button>` that toggles the panel and
+/// carries `aria-expanded`/`aria-controls`. Opening moves focus into the
+/// panel; Escape closes it and returns focus to the trigger, while clicking
+/// outside or tabbing away just closes it. Pass `open` to control it from
+/// outside.
+#[component]
+pub fn Popover(
+    #[prop(into)] trigger: ViewFn,
+    #[prop(optional)] open: Option<RwSignal<bool>>,
+    #[prop(optional)] placement: Option<Placement>,
+    /// Accessible name for the panel.
+    #[prop(optional, into)] label: Option<String>,
+    #[prop(into, optional)] trigger_class: Option<String>,
+    #[prop(optional, into)] class: Option<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let id = unique_id("popover");
+    let open = open.unwrap_or_else(|| RwSignal::new(false));
+    let trigger_ref = NodeRef::<html::Button>::new();
+    let panel = NodeRef::<html::Div>::new();
+
+    let options = PositionOptions::new(placement.unwrap_or(Placement::BOTTOM)).offset(10.0).arrow(ARROW);
+    let position = use_position(move || trigger_ref.get_untracked().map(Into::into), panel, open.into(), options);
+
+    let close = move |refocus: bool| {
+        open.set(false);
+        if refocus {
+            if let Some(trigger) = trigger_ref.get_untracked() {
+                let _ = trigger.focus();
+            }
+        }
+    };
+
+    // Focus moves in once the panel has been positioned, so the page
+    // doesn't scroll to where it was first laid out.
+    Effect::new(move |focused: Option<bool>| {
+        let placed = open.get() && position.get().is_some();
+        if placed && !focused.unwrap_or(false) {
+            if let Some(panel) = panel.get_untracked() {
+                focus::focus_initial(&panel, None);
+            }
+        }
+        placed
+    });
+
+    let outside_click = StoredValue::new_local(None::<WindowListenerHandle>);
+    Effect::new(move |_| {
+        if open.get() {
+            let handle = window_event_listener(ev::pointerdown, move |ev| {
+                let ev: &ev::Event = ev.as_ref();
+                let inside = event_within(ev, panel.get_untracked())
+                    || event_within(ev, trigger_ref.get_untracked());
+                if !inside {
+                    close(false);
+                }
+            });
+            outside_click.set_value(Some(handle));
+        } else if let Some(handle) = outside_click.try_update_value(Option::take).flatten() {
+            handle.remove();
+        }
+    });
+    on_cleanup(move || {
+        if let Some(Some(handle)) = outside_click.try_update_value(Option::take) {
+            handle.remove();
+        }
+    });
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() == "Escape" {
+            ev.prevent_default();
+            ev.stop_propagation();
+            close(true);
+        }
+    };
+    let on_focusout = move |ev: ev::FocusEvent| {
+        let next = ev.related_target().and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        let within = |el: Option<web_sys::Element>| matches!((el, &next), (Some(el), Some(next)) if el.contains(Some(next)));
+        // `None` means focus left the document or went to a non-focusable
+        // spot inside the panel; the outside click handler covers the rest.
+        if next.is_some()
+            && !within(panel.get_untracked().map(Into::into))
+            && !within(trigger_ref.get_untracked().map(Into::into))
+        {
+            open.set(false);
+        }
+    };
+
+    let label = StoredValue::new(label);
+    let panel_id = StoredValue::new(id.clone());
+    let children = StoredValue::new(children);
+    let panel_class = StoredValue::new(format!(
+        "fixed z-[60] min-w-[12rem] max-w-sm p-4 rounded-lg bg-surface-elevated border border-border text-text-primary shadow-[0_0_12px_2px_theme(colors.glow)] focus:outline-none {}",
+        class.unwrap_or_default()
+    ));
+
+    view! {
+        <button
+            node_ref=trigger_ref
+            type="button"
+            class=trigger_class.unwrap_or_else(|| "btn-secondary inline-flex items-center gap-2".to_string())
+            aria-haspopup="dialog"
+            aria-expanded=move || open.get().to_string()
+            aria-controls=id
+            on:click=move |_| open.update(|open| *open = !*open)
+        >
+            {trigger.run()}
+        </button>
+        <Show when=move || open.get()>
+            <Portal>
+                <div
+                    node_ref=panel
+                    id=panel_id.get_value()
+                    role="dialog"
+                    aria-label=label.get_value()
+                    tabindex="-1"
+                    class=panel_class.get_value()
+                    class:invisible=move || position.get().is_none()
+                    style=move || position.get().map(|position| position.style()).unwrap_or_default()
+                    on:keydown=on_keydown
+                    on:focusout=on_focusout
+                >
+                    {children.with_value(|children| children())}
+                    <span
+                        class=move || format!(
+                            "absolute w-2.5 h-2.5 rotate-45 bg-surface-elevated border-border {}",
+                            position.get().map(|position| position.placement.side.arrow_border()).unwrap_or_default()
+                        )
+                        style=move || position.get().map(|position| position.arrow_style()).unwrap_or_default()
+                        aria-hidden="true"
+                    ></span>
+                </div>
+            </Portal>
+        </Show>
+    }
+}
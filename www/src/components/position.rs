@@ -0,0 +1,443 @@
+//! Anchored positioning for tooltips, popovers and menus.
+//!
+//! [`compute_position`] is pure geometry: given the anchor, the floating
+//! element and the viewport it picks a side (flipping when the preferred one
+//! doesn't fit), shifts the element back inside the viewport and works out
+//! where the arrow should point. [`use_position`] measures the DOM and keeps
+//! the result current while the overlay is open. Coordinates are viewport
+//! relative, so floating elements use `position: fixed`.
+
+use leptos::html;
+use leptos::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+/// A viewport-relative box, as returned by `getBoundingClientRect`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn of(el: &Element) -> Self {
+        let rect = el.get_bounding_client_rect();
+        Self::new(rect.x(), rect.y(), rect.width(), rect.height())
+    }
+
+    /// The visible viewport, excluding a vertical scrollbar.
+    pub fn viewport() -> Self {
+        let height = window().inner_height().ok().and_then(|height| height.as_f64()).unwrap_or_default();
+        let width = document()
+            .document_element()
+            .map(|el| el.client_width() as f64)
+            .or_else(|| window().inner_width().ok().and_then(|width| width.as_f64()))
+            .unwrap_or_default();
+        Self::new(0.0, 0.0, width, height)
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Side {
+    Top,
+    Right,
+    #[default]
+    Bottom,
+    Left,
+}
+
+impl Side {
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Right => Side::Left,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+        }
+    }
+
+    /// Whether the floating element sits above or below the anchor.
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+
+    /// Border classes for a rotated-square arrow on an element placed on
+    /// this side, so only the two edges facing the anchor are outlined.
+    pub fn arrow_border(&self) -> &'static str {
+        match self {
+            Side::Top => "border-b border-r",
+            Side::Right => "border-b border-l",
+            Side::Bottom => "border-t border-l",
+            Side::Left => "border-t border-r",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::Top => "top",
+            Side::Right => "right",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+        }
+    }
+}
+
+/// Alignment along the anchor's edge.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Align {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+/// Preferred side and alignment; the default is centered below the anchor.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Placement {
+    pub side: Side,
+    pub align: Align,
+}
+
+impl Placement {
+    pub const TOP: Self = Self::new(Side::Top, Align::Center);
+    pub const RIGHT: Self = Self::new(Side::Right, Align::Center);
+    pub const BOTTOM: Self = Self::new(Side::Bottom, Align::Center);
+    pub const LEFT: Self = Self::new(Side::Left, Align::Center);
+    pub const BOTTOM_START: Self = Self::new(Side::Bottom, Align::Start);
+    pub const BOTTOM_END: Self = Self::new(Side::Bottom, Align::End);
+    pub const RIGHT_START: Self = Self::new(Side::Right, Align::Start);
+
+    pub const fn new(side: Side, align: Align) -> Self {
+        Self { side, align }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PositionOptions {
+    pub placement: Placement,
+    /// Gap between anchor and floating element, in px.
+    pub offset: f64,
+    /// Minimum distance kept from the viewport edges, in px.
+    pub padding: f64,
+    /// Move to the opposite side when the preferred one doesn't fit.
+    pub flip: bool,
+    /// Slide along the anchor's edge to stay inside the viewport.
+    pub shift: bool,
+    /// Side length of the (square, rotated) arrow; 0 for none.
+    pub arrow_size: f64,
+}
+
+impl Default for PositionOptions {
+    fn default() -> Self {
+        Self { placement: Placement::default(), offset: 8.0, padding: 8.0, flip: true, shift: true, arrow_size: 0.0 }
+    }
+}
+
+impl PositionOptions {
+    pub fn new(placement: Placement) -> Self {
+        Self { placement, ..Self::default() }
+    }
+
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn arrow(mut self, size: f64) -> Self {
+        self.arrow_size = size;
+        self
+    }
+}
+
+/// Where to put the floating element, and its arrow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+    /// The placement actually used, after flipping.
+    pub placement: Placement,
+    /// Offset of the arrow along the floating element's edge facing the
+    /// anchor, so it keeps pointing at the anchor's center after a shift.
+    pub arrow: f64,
+    pub arrow_size: f64,
+}
+
+impl Position {
+    /// Inline style for the floating element.
+    pub fn style(&self) -> String {
+        format!("left: {}px; top: {}px", self.x.round(), self.y.round())
+    }
+
+    /// Inline style for an absolutely positioned arrow inside the floating
+    /// element, half of it poking out towards the anchor.
+    pub fn arrow_style(&self) -> String {
+        let edge = self.placement.side.opposite().as_str();
+        let along = if self.placement.side.is_vertical() { "left" } else { "top" };
+        format!("{}: {}px; {}: {}px", along, self.arrow.round(), edge, -(self.arrow_size / 2.0).round())
+    }
+}
+
+/// Coordinates for `floating` on `placement` of `anchor`, ignoring collisions.
+fn place(anchor: &Rect, floating: &Rect, placement: Placement, offset: f64) -> (f64, f64) {
+    let cross = |start: f64, anchor_len: f64, floating_len: f64| match placement.align {
+        Align::Start => start,
+        Align::Center => start + (anchor_len - floating_len) / 2.0,
+        Align::End => start + anchor_len - floating_len,
+    };
+
+    match placement.side {
+        Side::Top => (cross(anchor.x, anchor.width, floating.width), anchor.y - floating.height - offset),
+        Side::Bottom => (cross(anchor.x, anchor.width, floating.width), anchor.bottom() + offset),
+        Side::Left => (anchor.x - floating.width - offset, cross(anchor.y, anchor.height, floating.height)),
+        Side::Right => (anchor.right() + offset, cross(anchor.y, anchor.height, floating.height)),
+    }
+}
+
+/// Room between the anchor and the viewport edge on `side`.
+fn space(anchor: &Rect, viewport: &Rect, side: Side) -> f64 {
+    match side {
+        Side::Top => anchor.y - viewport.y,
+        Side::Bottom => viewport.bottom() - anchor.bottom(),
+        Side::Left => anchor.x - viewport.x,
+        Side::Right => viewport.right() - anchor.right(),
+    }
+}
+
+/// Position `floating` next to `anchor` inside `viewport`.
+pub fn compute_position(anchor: Rect, floating: Rect, viewport: Rect, options: PositionOptions) -> Position {
+    let mut placement = options.placement;
+    let needed = |side: Side| {
+        let len = if side.is_vertical() { floating.height } else { floating.width };
+        len + options.offset + options.padding
+    };
+
+    if options.flip && space(&anchor, &viewport, placement.side) < needed(placement.side) {
+        let opposite = placement.side.opposite();
+        let fits = space(&anchor, &viewport, opposite) >= needed(opposite);
+        // Neither side fits: take whichever has more room.
+        let roomier = space(&anchor, &viewport, opposite) > space(&anchor, &viewport, placement.side);
+        if fits || roomier {
+            placement.side = opposite;
+        }
+    }
+
+    let (mut x, mut y) = place(&anchor, &floating, placement, options.offset);
+
+    if options.shift {
+        let clamp = |value: f64, min: f64, max: f64| if max < min { min } else { value.clamp(min, max) };
+        if placement.side.is_vertical() {
+            x = clamp(x, viewport.x + options.padding, viewport.right() - options.padding - floating.width);
+        } else {
+            y = clamp(y, viewport.y + options.padding, viewport.bottom() - options.padding - floating.height);
+        }
+    }
+
+    // Keep the arrow off the rounded corners.
+    let inset = options.arrow_size;
+    let arrow = if placement.side.is_vertical() {
+        let target = anchor.x + anchor.width / 2.0 - x - options.arrow_size / 2.0;
+        target.clamp(inset.min(floating.width / 2.0), (floating.width - options.arrow_size - inset).max(inset))
+    } else {
+        let target = anchor.y + anchor.height / 2.0 - y - options.arrow_size / 2.0;
+        target.clamp(inset.min(floating.height / 2.0), (floating.height - options.arrow_size - inset).max(inset))
+    };
+
+    Position { x, y, placement, arrow, arrow_size: options.arrow_size }
+}
+
+/// Keep `floating` positioned against the element returned by `anchor`
+/// while `open` is true.
+///
+/// The position is measured a frame after opening (once the floating
+/// element has laid out) and again on any scroll, including scrolling
+/// containers, and on resize. It is `None` while closed or not yet measured;
+/// render the floating element invisible until then.
+pub fn use_position(
+    anchor: impl Fn() -> Option<Element> + Copy + 'static,
+    floating: NodeRef<html::Div>,
+    open: Signal<bool>,
+    options: PositionOptions,
+) -> ReadSignal<Option<Position>> {
+    let (position, set_position) = signal(None::<Position>);
+    let listener = StoredValue::new_local(None::<Closure<dyn Fn()>>);
+    let pending = StoredValue::new(false);
+
+    let update = move || {
+        let (Some(anchor), Some(floating)) = (anchor(), floating.get_untracked()) else {
+            return;
+        };
+        let next = compute_position(Rect::of(&anchor), Rect::of(&floating), Rect::viewport(), options);
+        if position.try_get_untracked().flatten() != Some(next) {
+            set_position.try_set(Some(next));
+        }
+    };
+
+    // Coalesce bursts of scroll events into one update per frame.
+    let schedule = move || {
+        if pending.try_get_value().unwrap_or(true) {
+            return;
+        }
+        pending.set_value(true);
+        request_animation_frame(move || {
+            if pending.try_set_value(false).is_none() {
+                update();
+            }
+        });
+    };
+
+    let detach = move || {
+        if let Some(Some(closure)) = listener.try_update_value(Option::take) {
+            let callback = closure.as_ref().unchecked_ref();
+            let _ = window().remove_event_listener_with_callback_and_bool("scroll", callback, true);
+            let _ = window().remove_event_listener_with_callback("resize", callback);
+        }
+    };
+
+    Effect::new(move |_| {
+        // Also rerun when the floating element mounts, for overlays that
+        // only render while open.
+        floating.track();
+        if open.get() {
+            if listener.with_value(Option::is_none) {
+                let closure = Closure::<dyn Fn()>::new(schedule);
+                let callback = closure.as_ref().unchecked_ref();
+                // Capture, so scrolling any ancestor container is seen too.
+                let _ = window().add_event_listener_with_callback_and_bool("scroll", callback, true);
+                let _ = window().add_event_listener_with_callback("resize", callback);
+                listener.set_value(Some(closure));
+            }
+            schedule();
+        } else {
+            detach();
+            set_position.set(None);
+        }
+    });
+
+    on_cleanup(detach);
+
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect { x: 0.0, y: 0.0, width: 800.0, height: 600.0 };
+    const FLOATING: Rect = Rect { x: 0.0, y: 0.0, width: 100.0, height: 40.0 };
+
+    fn at(anchor: Rect, placement: Placement) -> Position {
+        compute_position(anchor, FLOATING, VIEWPORT, PositionOptions::new(placement))
+    }
+
+    #[test]
+    fn keeps_the_preferred_side_when_it_fits() {
+        let position = at(Rect::new(350.0, 100.0, 100.0, 20.0), Placement::BOTTOM);
+        assert_eq!(position.placement, Placement::BOTTOM);
+        assert_eq!((position.x, position.y), (350.0, 128.0));
+    }
+
+    #[test]
+    fn flips_away_from_each_viewport_edge() {
+        let position = at(Rect::new(350.0, 560.0, 100.0, 20.0), Placement::BOTTOM);
+        assert_eq!(position.placement.side, Side::Top);
+        assert_eq!(position.y, 560.0 - 40.0 - 8.0);
+
+        let position = at(Rect::new(350.0, 10.0, 100.0, 20.0), Placement::TOP);
+        assert_eq!(position.placement.side, Side::Bottom);
+        assert_eq!(position.y, 30.0 + 8.0);
+
+        let position = at(Rect::new(750.0, 300.0, 40.0, 20.0), Placement::RIGHT);
+        assert_eq!(position.placement.side, Side::Left);
+        assert_eq!((position.x, position.y), (750.0 - 100.0 - 8.0, 290.0));
+
+        let position = at(Rect::new(10.0, 300.0, 40.0, 20.0), Placement::LEFT);
+        assert_eq!(position.placement.side, Side::Right);
+        assert_eq!(position.x, 50.0 + 8.0);
+    }
+
+    #[test]
+    fn flip_keeps_alignment() {
+        let position = at(Rect::new(350.0, 560.0, 100.0, 20.0), Placement::BOTTOM_END);
+        assert_eq!(position.placement, Placement::new(Side::Top, Align::End));
+    }
+
+    #[test]
+    fn takes_the_roomier_side_when_neither_fits() {
+        let viewport = Rect::new(0.0, 0.0, 800.0, 100.0);
+        let options = PositionOptions::new(Placement::BOTTOM);
+
+        let position = compute_position(Rect::new(350.0, 30.0, 100.0, 20.0), FLOATING, viewport, options);
+        assert_eq!(position.placement.side, Side::Bottom);
+
+        let position = compute_position(Rect::new(350.0, 50.0, 100.0, 20.0), FLOATING, viewport, options);
+        assert_eq!(position.placement.side, Side::Top);
+    }
+
+    #[test]
+    fn does_not_flip_when_disabled() {
+        let options = PositionOptions { flip: false, ..PositionOptions::new(Placement::BOTTOM) };
+        let position = compute_position(Rect::new(350.0, 560.0, 100.0, 20.0), FLOATING, VIEWPORT, options);
+        assert_eq!(position.placement.side, Side::Bottom);
+    }
+
+    #[test]
+    fn shift_clamps_to_the_padded_viewport() {
+        assert_eq!(at(Rect::new(0.0, 100.0, 40.0, 20.0), Placement::BOTTOM).x, 8.0);
+        assert_eq!(at(Rect::new(760.0, 100.0, 40.0, 20.0), Placement::BOTTOM).x, 800.0 - 8.0 - 100.0);
+        assert_eq!(at(Rect::new(100.0, 0.0, 40.0, 20.0), Placement::RIGHT).y, 8.0);
+        assert_eq!(at(Rect::new(100.0, 590.0, 40.0, 10.0), Placement::RIGHT).y, 600.0 - 8.0 - 40.0);
+
+        // Wider than the viewport: pin to the leading edge.
+        let wide = Rect::new(0.0, 0.0, 900.0, 40.0);
+        let options = PositionOptions::new(Placement::BOTTOM);
+        assert_eq!(compute_position(Rect::new(350.0, 100.0, 100.0, 20.0), wide, VIEWPORT, options).x, 8.0);
+
+        let options = PositionOptions { shift: false, ..options };
+        assert_eq!(compute_position(Rect::new(0.0, 100.0, 40.0, 20.0), FLOATING, VIEWPORT, options).x, -30.0);
+    }
+
+    #[test]
+    fn arrow_points_at_the_anchor_center() {
+        let options = PositionOptions::new(Placement::BOTTOM).arrow(10.0);
+        let position = compute_position(Rect::new(350.0, 100.0, 100.0, 20.0), FLOATING, VIEWPORT, options);
+        assert_eq!(position.arrow, 50.0 - 5.0);
+
+        let options = PositionOptions::new(Placement::RIGHT).arrow(10.0);
+        let position = compute_position(Rect::new(100.0, 300.0, 40.0, 20.0), FLOATING, VIEWPORT, options);
+        assert_eq!(position.arrow, 20.0 - 5.0);
+    }
+
+    #[test]
+    fn arrow_stays_off_the_corners_after_a_shift() {
+        let options = PositionOptions::new(Placement::BOTTOM).arrow(10.0);
+
+        let position = compute_position(Rect::new(0.0, 100.0, 40.0, 20.0), FLOATING, VIEWPORT, options);
+        assert_eq!(position.x, 8.0);
+        assert_eq!(position.arrow, 10.0);
+
+        let position = compute_position(Rect::new(780.0, 100.0, 20.0, 20.0), FLOATING, VIEWPORT, options);
+        assert_eq!(position.arrow, 100.0 - 10.0 - 10.0);
+    }
+
+    #[test]
+    fn arrow_style_pokes_out_towards_the_anchor() {
+        let options = PositionOptions::new(Placement::BOTTOM).arrow(10.0);
+        let position = compute_position(Rect::new(350.0, 100.0, 100.0, 20.0), FLOATING, VIEWPORT, options);
+        assert_eq!(position.arrow_style(), "left: 45px; top: -5px");
+    }
+}
@@ -0,0 +1,69 @@
+//! Roving tabindex helpers shared by composite widgets (button groups, menus).
+//!
+//! Only the active item of a composite is in the tab order (`tabindex="0"`);
+//! arrow keys move focus between siblings. Items must be direct children of
+//! the same parent element.
+
+use leptos::ev::KeyboardEvent;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// The index focus should move to for `key`, or `None` if the key isn't a
+/// navigation key for this orientation. Wraps at both ends.
+pub fn next_index(key: &str, current: usize, count: usize, orientation: Orientation) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let (prev_key, next_key) = match orientation {
+        Orientation::Horizontal => ("ArrowLeft", "ArrowRight"),
+        Orientation::Vertical => ("ArrowUp", "ArrowDown"),
+    };
+
+    match key {
+        "Home" => Some(0),
+        "End" => Some(count - 1),
+        k if k == prev_key => Some((current + count - 1) % count),
+        k if k == next_key => Some((current + 1) % count),
+        _ => None,
+    }
+}
+
+/// `tabindex` value for item `index` when `active` is the roving item.
+pub fn tab_index(index: usize, active: usize) -> &'static str {
+    if index == active {
+        "0"
+    } else {
+        "-1"
+    }
+}
+
+/// Focus the sibling at `index` of the element that received `ev`.
+pub fn focus_sibling(ev: &KeyboardEvent, index: usize) {
+    let sibling = ev
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|item| item.parent_element())
+        .and_then(|parent| parent.children().item(index as u32))
+        .and_then(|sibling| sibling.dyn_into::<HtmlElement>().ok());
+
+    if let Some(sibling) = sibling {
+        let _ = sibling.focus();
+    }
+}
+
+/// Handle a keydown on item `current` of `count` siblings: prevents the
+/// default scroll, focuses the target sibling and returns its index.
+pub fn handle_keydown(ev: &KeyboardEvent, current: usize, count: usize, orientation: Orientation) -> Option<usize> {
+    let next = next_index(&ev.key(), current, count, orientation)?;
+    ev.prevent_default();
+    focus_sibling(ev, next);
+    Some(next)
+}
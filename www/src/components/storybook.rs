@@ -0,0 +1,597 @@
+//! A small storybook: components declare named stories with editable props,
+//! and `StoryBook` renders them with live controls, a theme switcher and the
+//! code for the current props.
+//!
+//! Each control edits one prop. Render functions read the current values
+//! from [`Args`] (`text`, `flag`, `number`, `choice`) as signals, so the
+//! preview updates in place. Enum props implement [`StoryEnum`], usually via
+//! `story_enum!`, to list their variants. Generated snippets only spell out
+//! props that differ from the component's default, like code written by hand.
+
+use std::sync::Arc;
+
+use leptos::prelude::*;
+
+use crate::components::button_group::{ButtonGroup, ToggleOption};
+use crate::components::code_block::CodeBlock;
+use crate::components::hash;
+use crate::components::inputs::{Select, SelectOption, Slider, Switch, TextInput};
+use crate::components::theme::{Theme, ThemeContext};
+use crate::components::typography::{Heading, HeadingLevel};
+
+/// An enum prop that can be picked from a dropdown.
+pub trait StoryEnum: Copy + PartialEq + Send + Sync + 'static {
+    /// The type name, as written in snippets.
+    const TYPE: &'static str;
+    const ALL: &'static [Self];
+
+    /// The variant name, as written in snippets.
+    fn name(&self) -> &'static str;
+
+    /// The component's default, or `None` for required props.
+    fn default_variant() -> Option<Self>;
+}
+
+/// Implement [`StoryEnum`] for a fieldless enum by listing its variants.
+/// Enums without a `Default` impl, used for required props, add `required`.
+macro_rules! story_enum {
+    ($type:ident { $($variant:ident),* $(,)? }) => {
+        story_enum!(@impl $type { $($variant),* } Some(<$type as Default>::default()));
+    };
+    ($type:ident { $($variant:ident),* $(,)? }, required) => {
+        story_enum!(@impl $type { $($variant),* } None);
+    };
+    (@impl $type:ident { $($variant:ident),* } $default:expr) => {
+        impl $crate::components::storybook::StoryEnum for $type {
+            const TYPE: &'static str = stringify!($type);
+            const ALL: &'static [Self] = &[$($type::$variant),*];
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $($type::$variant => stringify!($variant),)*
+                }
+            }
+
+            fn default_variant() -> Option<Self> {
+                $default
+            }
+        }
+    };
+}
+pub(crate) use story_enum;
+
+#[derive(Clone, Debug, PartialEq)]
+enum ControlKind {
+    Text,
+    Flag,
+    Number { min: f64, max: f64, step: f64 },
+    Choice { type_name: &'static str, options: Vec<&'static str> },
+    /// Text passed as the component's children.
+    Children,
+}
+
+#[derive(Clone, Debug)]
+struct Control {
+    prop: &'static str,
+    kind: ControlKind,
+    /// Value when the story opens.
+    initial: String,
+    /// The component's own default; props at this value are left out of
+    /// snippets.
+    default: String,
+}
+
+#[derive(Clone, Copy)]
+enum ArgSignal {
+    Text(RwSignal<String>),
+    Flag(RwSignal<bool>),
+    Number(RwSignal<f64>),
+}
+
+impl ArgSignal {
+    fn value(&self) -> String {
+        match self {
+            ArgSignal::Text(value) => value.get(),
+            ArgSignal::Flag(value) => value.get().to_string(),
+            ArgSignal::Number(value) => value.get().to_string(),
+        }
+    }
+}
+
+/// The live prop values of an open story.
+#[derive(Clone)]
+pub struct Args {
+    values: Arc<Vec<(&'static str, ArgSignal)>>,
+}
+
+impl Args {
+    fn new(controls: &[Control]) -> Self {
+        let values = controls
+            .iter()
+            .map(|control| {
+                let signal = match control.kind {
+                    ControlKind::Flag => ArgSignal::Flag(RwSignal::new(control.initial == "true")),
+                    ControlKind::Number { .. } => ArgSignal::Number(RwSignal::new(control.initial.parse().unwrap_or_default())),
+                    _ => ArgSignal::Text(RwSignal::new(control.initial.clone())),
+                };
+                (control.prop, signal)
+            })
+            .collect();
+        Self { values: Arc::new(values) }
+    }
+
+    /// The signal for `prop`. A prop the story didn't declare logs an error
+    /// and reads as an empty, uneditable value rather than taking the whole
+    /// storybook down.
+    fn get(&self, prop: &str) -> ArgSignal {
+        self.values.iter().find(|(name, _)| *name == prop).map(|(_, signal)| *signal).unwrap_or_else(|| {
+            log::error!("story reads undeclared prop `{}`", prop);
+            ArgSignal::Text(RwSignal::new(String::new()))
+        })
+    }
+
+    pub fn text(&self, prop: &str) -> Signal<String> {
+        match self.get(prop) {
+            ArgSignal::Text(value) => value.into(),
+            other => Signal::derive(move || other.value()),
+        }
+    }
+
+    pub fn flag(&self, prop: &str) -> Signal<bool> {
+        match self.get(prop) {
+            ArgSignal::Flag(value) => value.into(),
+            other => Signal::derive(move || other.value() == "true"),
+        }
+    }
+
+    pub fn number(&self, prop: &str) -> Signal<f64> {
+        match self.get(prop) {
+            ArgSignal::Number(value) => value.into(),
+            other => Signal::derive(move || other.value().parse().unwrap_or_default()),
+        }
+    }
+
+    pub fn choice<T: StoryEnum>(&self, prop: &str) -> Signal<T> {
+        let value = self.get(prop);
+        Signal::derive(move || {
+            let name = value.value();
+            T::ALL.iter().copied().find(|variant| variant.name() == name).unwrap_or(T::ALL[0])
+        })
+    }
+}
+
+type RenderFn = Arc<dyn Fn(&Args) -> AnyView + Send + Sync>;
+type CodeFn = Arc<dyn Fn(&Args) -> String + Send + Sync>;
+
+/// One named state of a component, with the props that can be edited.
+#[derive(Clone)]
+pub struct Story {
+    pub component: &'static str,
+    pub name: &'static str,
+    pub description: Option<&'static str>,
+    controls: Vec<Control>,
+    render: RenderFn,
+    body: Option<&'static str>,
+    code: Option<CodeFn>,
+}
+
+impl Story {
+    pub fn new(
+        component: &'static str,
+        name: &'static str,
+        render: impl Fn(&Args) -> AnyView + Send + Sync + 'static,
+    ) -> Self {
+        Self { component, name, description: None, controls: Vec::new(), render: Arc::new(render), body: None, code: None }
+    }
+
+    pub fn description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    fn control(mut self, prop: &'static str, kind: ControlKind, initial: String, default: String) -> Self {
+        self.controls.push(Control { prop, kind, initial, default });
+        self
+    }
+
+    pub fn text(self, prop: &'static str, initial: &str) -> Self {
+        self.control(prop, ControlKind::Text, initial.to_string(), String::new())
+    }
+
+    pub fn flag(self, prop: &'static str, initial: bool) -> Self {
+        self.control(prop, ControlKind::Flag, initial.to_string(), false.to_string())
+    }
+
+    /// A numeric prop edited with a slider; `default` is the component's.
+    pub fn number(self, prop: &'static str, initial: f64, default: f64, (min, max, step): (f64, f64, f64)) -> Self {
+        self.control(prop, ControlKind::Number { min, max, step }, initial.to_string(), default.to_string())
+    }
+
+    pub fn choice<T: StoryEnum>(self, prop: &'static str, initial: T) -> Self {
+        let kind = ControlKind::Choice { type_name: T::TYPE, options: T::ALL.iter().map(StoryEnum::name).collect() };
+        let default = T::default_variant().map(|variant| variant.name().to_string()).unwrap_or_default();
+        self.control(prop, kind, initial.name().to_string(), default)
+    }
+
+    /// Editable text children.
+    pub fn children(self, initial: &str) -> Self {
+        self.control("children", ControlKind::Children, initial.to_string(), String::new())
+    }
+
+    /// Fixed markup shown between the tags in snippets, for stories whose
+    /// children are other components.
+    pub fn body(mut self, body: &'static str) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Replace the generated snippet, for props the generator can't spell.
+    pub fn code(mut self, code: impl Fn(&Args) -> String + Send + Sync + 'static) -> Self {
+        self.code = Some(Arc::new(code));
+        self
+    }
+
+    /// Deep-link id, e.g. `button--loading`.
+    pub fn id(&self) -> String {
+        let slug = |text: &str| {
+            text.to_lowercase().split(|c: char| !c.is_alphanumeric()).filter(|part| !part.is_empty()).collect::<Vec<_>>().join("-")
+        };
+        format!("{}--{}", slug(self.component), slug(self.name))
+    }
+
+    /// `view!` code for the current `args`.
+    pub fn snippet(&self, args: &Args) -> String {
+        if let Some(code) = &self.code {
+            return code(args);
+        }
+
+        let mut props = Vec::new();
+        let mut children = self.body.map(str::to_string);
+        for control in &self.controls {
+            let value = args.get(control.prop).value();
+            match &control.kind {
+                ControlKind::Children => children = Some(format!("{:?}", value)),
+                _ if value == control.default => {}
+                ControlKind::Text => props.push(format!("{}={:?}", control.prop, value)),
+                ControlKind::Flag | ControlKind::Number { .. } => props.push(format!("{}={}", control.prop, value)),
+                ControlKind::Choice { type_name, .. } => props.push(format!("{}={}::{}", control.prop, type_name, value)),
+            }
+        }
+
+        let tag = self.component;
+        let inline = format!("<{}{}", tag, props.iter().map(|prop| format!(" {}", prop)).collect::<String>());
+        let open = if inline.len() <= 72 {
+            inline
+        } else {
+            format!("<{}\n{}\n", tag, props.iter().map(|prop| format!("    {}", prop)).collect::<Vec<_>>().join("\n"))
+        };
+        let multiline = open.ends_with('\n');
+        match children {
+            None if multiline => format!("{}/>", open),
+            None => format!("{} />", open),
+            Some(children) if multiline || children.contains('\n') => {
+                let children = children.lines().map(|line| format!("    {}", line)).collect::<Vec<_>>().join("\n");
+                format!("{}>\n{}\n</{}>", open, children, tag)
+            }
+            Some(children) => format!("{}>{}</{}>", open, children, tag),
+        }
+    }
+
+    pub fn render(&self, args: &Args) -> AnyView {
+        (self.render)(args)
+    }
+}
+
+/// The stories of every component, in the order they were added.
+#[derive(Clone, Default)]
+pub struct StoryRegistry {
+    stories: Vec<Story>,
+}
+
+impl StoryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn story(mut self, story: Story) -> Self {
+        self.stories.push(story);
+        self
+    }
+
+    pub fn stories(&self) -> &[Story] {
+        &self.stories
+    }
+
+    /// Component names, in order of their first story.
+    pub fn components(&self) -> Vec<&'static str> {
+        let mut components = Vec::new();
+        for story in &self.stories {
+            if !components.contains(&story.component) {
+                components.push(story.component);
+            }
+        }
+        components
+    }
+
+    pub fn of<'a>(&'a self, component: &'a str) -> impl Iterator<Item = &'a Story> + 'a {
+        self.stories.iter().filter(move |story| story.component == component)
+    }
+
+    pub fn find(&self, id: &str) -> Option<&Story> {
+        self.stories.iter().find(|story| story.id() == id)
+    }
+}
+
+/// Theme switch for the whole page, so stories can be checked in each theme.
+#[component]
+fn ThemeSwitcher() -> impl IntoView {
+    const THEMES: [(Theme, &str); 3] =
+        [(Theme::Light, "Light"), (Theme::Dark, "Dark"), (Theme::HighContrast, "High contrast")];
+    let theme = use_context::<ThemeContext>()?.theme;
+    let selected = RwSignal::new(vec![theme.get_untracked().as_str().to_string()]);
+
+    Effect::new(move |_| {
+        let current = theme.get().as_str().to_string();
+        if selected.with_untracked(|selected| !selected.contains(&current)) {
+            selected.set(vec![current]);
+        }
+    });
+    Effect::new(move |_| {
+        let wanted = selected.with(|selected| {
+            THEMES.iter().map(|(theme, _)| *theme).find(|theme| selected.iter().any(|value| value == theme.as_str()))
+        });
+        if let Some(wanted) = wanted.filter(|wanted| *wanted != theme.get_untracked()) {
+            theme.set(wanted);
+        }
+    });
+
+    let options: Vec<ToggleOption> = THEMES.iter().map(|(theme, label)| ToggleOption::new(theme.as_str(), *label)).collect();
+    Some(view! {
+        <ButtonGroup
+            options=options
+            selected=selected
+            label="Theme"
+            size=crate::components::button::ButtonSize::Small
+        />
+    })
+}
+
+/// The input editing one control.
+fn control_input(control: &Control, args: &Args) -> AnyView {
+    let label = control.prop.replace('_', " ");
+    match (&control.kind, args.get(control.prop)) {
+        (ControlKind::Choice { options, .. }, ArgSignal::Text(value)) => {
+            let options = options.iter().map(|option| SelectOption::new(*option, *option)).collect();
+            view! { <Select value=value options=options label=label /> }.into_any()
+        }
+        (ControlKind::Number { min, max, step }, ArgSignal::Number(value)) => {
+            view! { <Slider value=value label=label min=*min max=*max step=*step /> }.into_any()
+        }
+        (_, ArgSignal::Flag(checked)) => view! { <Switch checked=checked label=label /> }.into_any(),
+        (_, ArgSignal::Text(value)) => view! { <TextInput value=value label=label /> }.into_any(),
+        _ => ().into_any(),
+    }
+}
+
+/// One story: its preview, controls and code.
+#[component]
+fn StoryView(story: Story) -> impl IntoView {
+    let args = Args::new(&story.controls);
+    let heading_id = format!("{}-heading", story.id());
+    let controls =
+        (!story.controls.is_empty()).then(|| story.controls.iter().map(|control| control_input(control, &args)).collect_view());
+    let preview = story.render(&args);
+    let code = StoredValue::new((story.clone(), args));
+
+    view! {
+        <section class="space-y-6" aria-labelledby=heading_id>
+            <header class="flex flex-wrap items-start justify-between gap-4">
+                <div>
+                    <p class="text-sm font-inter text-text-muted">{story.component}</p>
+                    <Heading level=HeadingLevel::H3 as_tag=HeadingLevel::H2 id=heading_id.clone()>
+                        {story.name}
+                    </Heading>
+                    {story.description.map(|description| view! {
+                        <p class="mt-1 font-inter text-text-secondary">{description}</p>
+                    })}
+                </div>
+                <ThemeSwitcher />
+            </header>
+            <div class="glass-card flex flex-wrap items-center justify-center gap-4 min-h-[12rem]">{preview}</div>
+            {controls.map(|controls| view! {
+                <div class="glass-card">
+                    <Heading level=HeadingLevel::H5 as_tag=HeadingLevel::H3 class="mb-4">"Props"</Heading>
+                    <div class="grid gap-4 sm:grid-cols-2">{controls}</div>
+                </div>
+            })}
+            {move || {
+                let snippet = code.with_value(|(story, args)| story.snippet(args));
+                view! { <CodeBlock code=snippet language="rust" /> }
+            }}
+        </section>
+    }
+}
+
+/// The stories of `registry` with a sidebar to pick one. The open story is
+/// kept in the URL hash, so stories can be linked to.
+#[component]
+pub fn StoryBook(registry: StoryRegistry) -> impl IntoView {
+    let first = registry.stories().first().map(Story::id).unwrap_or_default();
+    let selected = RwSignal::new(first);
+    let registry = StoredValue::new(registry);
+
+    hash::watch(move |hash| {
+        if registry.with_value(|registry| registry.find(&hash).is_some()) {
+            selected.set(hash);
+        }
+    });
+    let select = move |id: String| {
+        hash::replace(Some(&id));
+        selected.set(id);
+    };
+
+    let nav = registry.with_value(|registry| {
+        registry
+            .components()
+            .into_iter()
+            .map(|component| {
+                let links = registry
+                    .of(component)
+                    .map(|story| {
+                        let id = story.id();
+                        let current = {
+                            let id = id.clone();
+                            move || selected.with(|selected| *selected == id)
+                        };
+                        let (highlight, background, aria) = (current.clone(), current.clone(), current);
+                        view! {
+                            <li>
+                                <button
+                                    type="button"
+                                    class="w-full text-left px-3 py-1 rounded-md text-sm font-inter text-text-secondary hover:text-accent hover:bg-base02 focus:outline-none focus-visible:ring-2 focus-visible:ring-accent transition-colors"
+                                    class=("text-accent", highlight)
+                                    class=("bg-base02", background)
+                                    aria-current=move || aria().then_some("true")
+                                    on:click=move |_| select(id.clone())
+                                >
+                                    {story.name}
+                                </button>
+                            </li>
+                        }
+                    })
+                    .collect_view();
+                view! {
+                    <div>
+                        <p class="px-3 mb-1 text-xs font-orbitron uppercase tracking-wider text-text-muted">{component}</p>
+                        <ul class="space-y-0.5">{links}</ul>
+                    </div>
+                }
+            })
+            .collect_view()
+    });
+
+    view! {
+        <div class="grid gap-8 lg:grid-cols-[14rem_minmax(0,1fr)]">
+            <nav aria-label="Stories" class="space-y-4 lg:sticky lg:top-4 lg:self-start lg:max-h-[calc(100vh-2rem)] lg:overflow-y-auto">
+                {nav}
+            </nav>
+            <div class="min-w-0">
+                {move || {
+                    let id = selected.get();
+                    registry
+                        .with_value(|registry| registry.find(&id).cloned())
+                        .map(|story| view! { <StoryView story=story /> })
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Every story of `component` at its initial props, without controls.
+#[component]
+pub fn StoryGallery(registry: StoryRegistry, component: &'static str) -> impl IntoView {
+    registry
+        .of(component)
+        .map(|story| {
+            let args = Args::new(&story.controls);
+            view! {
+                <figure class="glass-card flex flex-col gap-4">
+                    <div class="flex flex-wrap items-center justify-center gap-4 min-h-[6rem]">{story.render(&args)}</div>
+                    <figcaption class="text-sm font-inter text-text-muted text-center">{story.name}</figcaption>
+                </figure>
+            }
+        })
+        .collect_view()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::button::ButtonVariant;
+
+    fn button() -> Story {
+        Story::new("Button", "Primary", |_| ().into_any())
+            .text("label", "")
+            .flag("disabled", false)
+            .choice("variant", ButtonVariant::Primary)
+            .number("size", 1.0, 1.0, (0.0, 2.0, 1.0))
+            .children("Click me")
+    }
+
+    fn args(story: &Story) -> Args {
+        Args::new(&story.controls)
+    }
+
+    #[test]
+    fn snippet_leaves_out_default_props() {
+        let story = button();
+        assert_eq!(story.snippet(&args(&story)), r#"<Button>"Click me"</Button>"#);
+    }
+
+    #[test]
+    fn snippet_spells_out_changed_props() {
+        let story = button();
+        let args = args(&story);
+        if let ArgSignal::Flag(disabled) = args.get("disabled") {
+            disabled.set(true);
+        }
+        if let ArgSignal::Text(variant) = args.get("variant") {
+            variant.set("Secondary".to_string());
+        }
+        if let ArgSignal::Number(size) = args.get("size") {
+            size.set(2.0);
+        }
+        assert_eq!(story.snippet(&args), r#"<Button disabled=true variant=ButtonVariant::Secondary size=2>"Click me"</Button>"#);
+    }
+
+    #[test]
+    fn snippet_wraps_props_past_72_columns() {
+        let story = Story::new("Button", "Long", |_| ().into_any())
+            .text("label", "A label long enough to push the tag past the limit")
+            .flag("disabled", true);
+        assert_eq!(
+            story.snippet(&args(&story)),
+            "<Button\n    label=\"A label long enough to push the tag past the limit\"\n    disabled=true\n/>"
+        );
+
+        let story = story.children("Go");
+        assert_eq!(
+            story.snippet(&args(&story)),
+            "<Button\n    label=\"A label long enough to push the tag past the limit\"\n    disabled=true\n>\n    \"Go\"\n</Button>"
+        );
+    }
+
+    #[test]
+    fn snippet_indents_multiline_children() {
+        let story = Story::new("Menu", "Items", |_| ().into_any()).body("<MenuItem />\n<MenuItem />");
+        assert_eq!(story.snippet(&args(&story)), "<Menu>\n    <MenuItem />\n    <MenuItem />\n</Menu>");
+    }
+
+    #[test]
+    fn snippet_without_props_or_children_self_closes() {
+        let story = Story::new("Spinner", "Default", |_| ().into_any());
+        assert_eq!(story.snippet(&args(&story)), "<Spinner />");
+    }
+
+    #[test]
+    fn code_replaces_the_generated_snippet() {
+        let story = button().code(|_| "custom".to_string());
+        assert_eq!(story.snippet(&args(&story)), "custom");
+    }
+
+    #[test]
+    fn id_slugs_component_and_name() {
+        let story = Story::new("Button Group", "Split / Dropdown!", |_| ().into_any());
+        assert_eq!(story.id(), "button-group--split-dropdown");
+    }
+
+    #[test]
+    fn undeclared_props_read_as_empty() {
+        let story = button();
+        let args = args(&story);
+        assert_eq!(args.text("lable").get_untracked(), "");
+        assert!(!args.flag("disabeld").get_untracked());
+        assert_eq!(args.number("sise").get_untracked(), 0.0);
+        assert_eq!(args.choice::<ButtonVariant>("varient").get_untracked(), ButtonVariant::Primary);
+    }
+}
@@ -0,0 +1,296 @@
+//! Tabs following the WAI-ARIA tabs pattern.
+//!
+//! `Tabs` owns the selection and hands it to `TabList`, `Tab` and `TabPanel`
+//! through context, so tabs and panels can sit anywhere beneath it. Tabs and
+//! panels are matched by `value`.
+
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+
+use crate::components::focus;
+use crate::components::hash;
+use crate::components::ids::next_id;
+use crate::components::roving::{self, Orientation};
+
+/// When arrow-key focus changes the selected tab.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Activation {
+    /// Moving focus selects the tab; best when panels are cheap to show.
+    #[default]
+    Automatic,
+    /// Focus moves freely and Enter or Space selects.
+    Manual,
+}
+
+#[derive(Clone, Copy)]
+struct TabsContext {
+    id: usize,
+    selected: RwSignal<String>,
+    /// Tab values in the order they were rendered, for arrow-key navigation.
+    tabs: RwSignal<Vec<String>>,
+    /// Panels that have been shown at least once, for lazy mounting.
+    visited: RwSignal<Vec<String>>,
+    activation: Activation,
+    orientation: Orientation,
+    lazy: bool,
+}
+
+impl TabsContext {
+    fn tab_id(&self, value: &str) -> String {
+        format!("tabs-{}-tab-{}", self.id, value)
+    }
+
+    fn panel_id(&self, value: &str) -> String {
+        format!("tabs-{}-panel-{}", self.id, value)
+    }
+}
+
+fn use_tabs() -> TabsContext {
+    expect_context::<TabsContext>()
+}
+
+/// Tab set root.
+///
+/// `selected` can be passed to control the selection from outside; otherwise
+/// it starts at `default_value` or the first tab. With `lazy`, a panel's
+/// content is only created the first time its tab is selected and then kept.
+/// With `sync_hash`, the selected value is mirrored into the URL hash
+/// (`#value`) so a view can be linked to, and a matching hash selects its tab
+/// on load and on `hashchange`. Use it for at most one tab set per page.
+#[component]
+pub fn Tabs(
+    #[prop(optional)] selected: Option<RwSignal<String>>,
+    #[prop(optional, into)] default_value: Option<String>,
+    #[prop(optional)] activation: Activation,
+    #[prop(optional)] orientation: Orientation,
+    #[prop(optional)] lazy: bool,
+    #[prop(optional)] sync_hash: bool,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let selected = selected.unwrap_or_else(|| RwSignal::new(default_value.unwrap_or_default()));
+    let context = TabsContext {
+        id: next_id(),
+        selected,
+        tabs: RwSignal::new(Vec::new()),
+        visited: RwSignal::new(Vec::new()),
+        activation,
+        orientation,
+        lazy,
+    };
+    provide_context(context);
+
+    Effect::new(move |_| {
+        let value = selected.get();
+        if !value.is_empty() && !context.visited.with_untracked(|visited| visited.contains(&value)) {
+            context.visited.update(|visited| visited.push(value));
+        }
+    });
+
+    if sync_hash {
+        // Tabs register while rendering; `watch` checks after the first frame.
+        hash::watch(move |hash| {
+            if context.tabs.with_untracked(|tabs| tabs.contains(&hash)) {
+                selected.set(hash);
+            }
+        });
+
+        Effect::new(move |previous: Option<String>| {
+            let value = selected.get();
+            // Leave the hash alone until the user actually switches tabs.
+            if previous.is_some_and(|previous| previous != value) && hash::current() != value {
+                hash::replace(Some(&value));
+            }
+            value
+        });
+    }
+
+    let flex = match orientation {
+        Orientation::Horizontal => "flex flex-col",
+        Orientation::Vertical => "flex flex-row gap-6",
+    };
+
+    view! {
+        <div class=format!("{} {}", flex, class.unwrap_or_default())>
+            {children()}
+        </div>
+    }
+}
+
+/// Container for `Tab`s, with the animated underline marking the selection.
+///
+/// Tabs must be its direct children.
+#[component]
+pub fn TabList(
+    /// Accessible name for the tab set.
+    #[prop(into)] label: String,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = use_tabs();
+    let list = NodeRef::<html::Div>::new();
+    let indicator = RwSignal::new(None::<(i32, i32)>);
+    let vertical = context.orientation == Orientation::Vertical;
+
+    // Measure the selected tab relative to the list.
+    let measure = move || {
+        let selected = context.selected.get_untracked();
+        let tab = list
+            .get_untracked()
+            .and_then(|list| list.query_selector(&format!("[data-value=\"{}\"]", selected)).ok().flatten())
+            .and_then(|tab| wasm_bindgen::JsCast::dyn_into::<web_sys::HtmlElement>(tab).ok());
+        indicator.try_set(tab.map(|tab| {
+            if vertical {
+                (tab.offset_top(), tab.offset_height())
+            } else {
+                (tab.offset_left(), tab.offset_width())
+            }
+        }));
+    };
+
+    Effect::new(move |_| {
+        context.selected.track();
+        context.tabs.track();
+        request_animation_frame(measure);
+    });
+    let listener = window_event_listener(ev::resize, move |_| measure());
+    on_cleanup(move || listener.remove());
+
+    let (list_class, bar_class) = if vertical {
+        ("relative flex flex-col border-r border-border", "right-0 w-0.5 transition-[top,height]")
+    } else {
+        ("relative flex border-b border-border overflow-x-auto", "bottom-0 h-0.5 transition-[left,width]")
+    };
+
+    view! {
+        <div
+            node_ref=list
+            role="tablist"
+            aria-label=label
+            aria-orientation=if vertical { "vertical" } else { "horizontal" }
+            class=format!("{} {}", list_class, class.unwrap_or_default())
+        >
+            {children()}
+            <span
+                class=format!(
+                    "absolute {} bg-accent shadow-[0_0_8px_2px_theme(colors.glow)] duration-300 ease-out motion-reduce:transition-none pointer-events-none",
+                    bar_class
+                )
+                class:hidden=move || indicator.get().is_none()
+                style=move || {
+                    indicator
+                        .get()
+                        .map(|(start, len)| {
+                            if vertical {
+                                format!("top: {}px; height: {}px", start, len)
+                            } else {
+                                format!("left: {}px; width: {}px", start, len)
+                            }
+                        })
+                        .unwrap_or_default()
+                }
+                aria-hidden="true"
+            ></span>
+        </div>
+    }
+}
+
+/// A tab selecting the `TabPanel` with the same `value`.
+#[component]
+pub fn Tab(
+    #[prop(into)] value: String,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = use_tabs();
+
+    context.tabs.update(|tabs| tabs.push(value.clone()));
+    if context.selected.get_untracked().is_empty() {
+        context.selected.set(value.clone());
+    }
+    let registered = value.clone();
+    on_cleanup(move || {
+        context.tabs.try_update(|tabs| tabs.retain(|tab| *tab != registered));
+    });
+
+    let value = StoredValue::new(value);
+    let is_selected = move || value.with_value(|value| context.selected.with(|selected| selected == value));
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let tabs = context.tabs.get_untracked();
+        let Some(current) = value.with_value(|value| tabs.iter().position(|tab| tab == value)) else {
+            return;
+        };
+        if let Some(next) = roving::handle_keydown(&ev, current, tabs.len(), context.orientation) {
+            if context.activation == Activation::Automatic {
+                context.selected.set(tabs[next].clone());
+            }
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            role="tab"
+            id=value.with_value(|value| context.tab_id(value))
+            data-value=value.get_value()
+            aria-selected=move || is_selected().to_string()
+            aria-controls=value.with_value(|value| context.panel_id(value))
+            tabindex=move || if is_selected() { "0" } else { "-1" }
+            class=format!(
+                "px-4 py-2 whitespace-nowrap font-outfit text-sm transition-colors hover:text-text-primary focus:outline-none focus-visible:ring-2 focus-visible:ring-inset focus-visible:ring-accent {}",
+                class.unwrap_or_default()
+            )
+            class=("text-accent", is_selected)
+            class=("text-text-secondary", move || !is_selected())
+            on:click=move |_| context.selected.set(value.get_value())
+            on:keydown=on_keydown
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// Content for the `Tab` with the same `value`; hidden while not selected.
+#[component]
+pub fn TabPanel(
+    #[prop(into)] value: String,
+    #[prop(optional, into)] class: Option<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = use_tabs();
+    let value = StoredValue::new(value);
+    let is_selected = move || value.with_value(|value| context.selected.with(|selected| selected == value));
+    let mounted = move || !context.lazy || value.with_value(|value| context.visited.with(|visited| visited.contains(value)));
+
+    let panel = NodeRef::<html::Div>::new();
+    // Panels without focusable content are themselves a tab stop, so
+    // keyboard users can reach and scroll them.
+    let focusable = RwSignal::new(true);
+    Effect::new(move |_| {
+        if is_selected() {
+            request_animation_frame(move || {
+                if let Some(panel) = panel.get_untracked() {
+                    focusable.try_set(focus::first_focusable(&panel).is_none());
+                }
+            });
+        }
+    });
+
+    view! {
+        <div
+            node_ref=panel
+            role="tabpanel"
+            id=value.with_value(|value| context.panel_id(value))
+            aria-labelledby=value.with_value(|value| context.tab_id(value))
+            tabindex=move || focusable.get().then_some("0")
+            hidden=move || !is_selected()
+            class=format!("pt-4 focus:outline-none focus-visible:ring-2 focus-visible:ring-accent rounded-md {}", class.unwrap_or_default())
+        >
+            <Show when=mounted>
+                {children()}
+            </Show>
+        </div>
+    }
+}
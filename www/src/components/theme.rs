@@ -61,6 +61,12 @@ impl ThemeContext {
     }
 }
 
+impl Default for ThemeContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[component]
 pub fn ThemeProvider(children: Children) -> impl IntoView {
     let theme_context = ThemeContext::new();
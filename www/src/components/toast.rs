@@ -0,0 +1,404 @@
+//! Transient notifications.
+//!
+//! [`ToastProvider`] renders the toast stack and provides a [`Toasts`]
+//! handle that [`use_toast`] returns anywhere below it:
+//!
+//! ```ignore
+//! let toast = use_toast();
+//! let id = toast.loading("Saving entry…");
+//! // later
+//! toast.promote(id, ToastKind::Success, "Entry saved");
+//! ```
+
+use std::future::Future;
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use crate::components::focus::prefers_reduced_motion;
+
+/// Matches the `duration-200` enter/exit transition.
+const TRANSITION: Duration = Duration::from_millis(200);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ToastKind {
+    Success,
+    Error,
+    Info,
+    /// Stays until promoted or dismissed.
+    Loading,
+}
+
+impl ToastKind {
+    pub fn class(&self) -> &'static str {
+        match self {
+            ToastKind::Success => "border-l-success",
+            ToastKind::Error => "border-l-error",
+            ToastKind::Info => "border-l-info",
+            ToastKind::Loading => "border-l-accent",
+        }
+    }
+
+    fn icon_class(&self) -> &'static str {
+        match self {
+            ToastKind::Success => "text-success",
+            ToastKind::Error => "text-error",
+            ToastKind::Info => "text-info",
+            ToastKind::Loading => "text-accent",
+        }
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            ToastKind::Success => "✓",
+            ToastKind::Error => "!",
+            ToastKind::Info => "i",
+            ToastKind::Loading => "",
+        }
+    }
+
+    /// How long a toast of this kind stays up by default.
+    pub fn default_duration(&self) -> Option<Duration> {
+        match self {
+            ToastKind::Success | ToastKind::Info => Some(Duration::from_secs(4)),
+            ToastKind::Error => Some(Duration::from_secs(6)),
+            ToastKind::Loading => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ToastPosition {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    #[default]
+    BottomRight,
+}
+
+impl ToastPosition {
+    pub fn class(&self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft => "top-4 left-4 items-start flex-col-reverse",
+            ToastPosition::TopCenter => "top-4 left-1/2 -translate-x-1/2 items-center flex-col-reverse",
+            ToastPosition::TopRight => "top-4 right-4 items-end flex-col-reverse",
+            ToastPosition::BottomLeft => "bottom-4 left-4 items-start flex-col",
+            ToastPosition::BottomCenter => "bottom-4 left-1/2 -translate-x-1/2 items-center flex-col",
+            ToastPosition::BottomRight => "bottom-4 right-4 items-end flex-col",
+        }
+    }
+}
+
+/// Identifies a toast for [`Toasts::promote`] and [`Toasts::dismiss`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+/// A button inside a toast, e.g. "Undo". Clicking it also dismisses the toast.
+#[derive(Clone)]
+pub struct ToastAction {
+    pub label: String,
+    pub on_click: Callback<()>,
+}
+
+impl ToastAction {
+    pub fn new(label: impl Into<String>, on_click: impl Fn() + Send + Sync + 'static) -> Self {
+        Self { label: label.into(), on_click: Callback::new(move |_| on_click()) }
+    }
+}
+
+/// Optional settings for [`Toasts::show`].
+#[derive(Clone, Default)]
+pub struct ToastOptions {
+    pub description: Option<String>,
+    pub action: Option<ToastAction>,
+    /// Overrides the kind's default duration; `Some(None)` keeps the toast
+    /// until dismissed.
+    pub duration: Option<Option<Duration>>,
+}
+
+#[derive(Clone)]
+struct ToastEntry {
+    id: ToastId,
+    kind: RwSignal<ToastKind>,
+    message: RwSignal<String>,
+    description: Option<String>,
+    action: Option<ToastAction>,
+    duration: RwSignal<Option<Duration>>,
+    leaving: RwSignal<bool>,
+}
+
+/// Handle for showing toasts, returned by [`use_toast`].
+#[derive(Clone, Copy)]
+pub struct Toasts {
+    toasts: RwSignal<Vec<ToastEntry>>,
+    next_id: StoredValue<u64>,
+    position: RwSignal<ToastPosition>,
+    max_visible: usize,
+    polite: RwSignal<String>,
+    assertive: RwSignal<String>,
+}
+
+impl Toasts {
+    fn new(position: ToastPosition, max_visible: usize) -> Self {
+        Self {
+            toasts: RwSignal::new(Vec::new()),
+            next_id: StoredValue::new(0),
+            position: RwSignal::new(position),
+            max_visible,
+            polite: RwSignal::new(String::new()),
+            assertive: RwSignal::new(String::new()),
+        }
+    }
+
+    pub fn show(&self, kind: ToastKind, message: impl Into<String>, options: ToastOptions) -> ToastId {
+        let id = ToastId(self.next_id.get_value());
+        self.next_id.update_value(|next| *next += 1);
+        let message = message.into();
+        self.announce(kind, &message);
+
+        let entry = ToastEntry {
+            id,
+            kind: RwSignal::new(kind),
+            message: RwSignal::new(message),
+            description: options.description,
+            action: options.action,
+            duration: RwSignal::new(options.duration.unwrap_or_else(|| kind.default_duration())),
+            leaving: RwSignal::new(false),
+        };
+        let max_visible = self.max_visible;
+        self.toasts.update(|toasts| {
+            toasts.push(entry);
+            // Drop the oldest once the stack is full.
+            let overflow = toasts.len().saturating_sub(max_visible);
+            toasts.drain(..overflow);
+        });
+        id
+    }
+
+    pub fn success(&self, message: impl Into<String>) -> ToastId {
+        self.show(ToastKind::Success, message, ToastOptions::default())
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ToastId {
+        self.show(ToastKind::Error, message, ToastOptions::default())
+    }
+
+    pub fn info(&self, message: impl Into<String>) -> ToastId {
+        self.show(ToastKind::Info, message, ToastOptions::default())
+    }
+
+    pub fn loading(&self, message: impl Into<String>) -> ToastId {
+        self.show(ToastKind::Loading, message, ToastOptions::default())
+    }
+
+    /// Turn toast `id` (usually a loading one) into `kind` with a new
+    /// message, restarting its timer with the new kind's duration.
+    pub fn promote(&self, id: ToastId, kind: ToastKind, message: impl Into<String>) {
+        let message = message.into();
+        let entry = self.toasts.with_untracked(|toasts| toasts.iter().find(|toast| toast.id == id).cloned());
+        match entry {
+            Some(entry) => {
+                self.announce(kind, &message);
+                entry.kind.set(kind);
+                entry.message.set(message);
+                entry.duration.set(kind.default_duration());
+            }
+            // Already dismissed: show the outcome anyway.
+            None => {
+                self.show(kind, message, ToastOptions::default());
+            }
+        }
+    }
+
+    /// Show a loading toast while `future` runs, then promote it to success
+    /// or error.
+    pub async fn track<T, E, Fut>(
+        &self,
+        future: Fut,
+        loading: impl Into<String>,
+        success: impl Into<String>,
+        error: impl Fn(&E) -> String,
+    ) -> Result<T, E>
+    where
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let id = self.loading(loading);
+        let result = future.await;
+        match &result {
+            Ok(_) => self.promote(id, ToastKind::Success, success),
+            Err(err) => self.promote(id, ToastKind::Error, error(err)),
+        }
+        result
+    }
+
+    /// Start the exit transition of toast `id` and remove it afterwards.
+    pub fn dismiss(&self, id: ToastId) {
+        let toasts = self.toasts;
+        let entry = toasts.with_untracked(|toasts| toasts.iter().find(|toast| toast.id == id).cloned());
+        let Some(entry) = entry else {
+            return;
+        };
+        entry.leaving.set(true);
+        let remove = move || toasts.try_update(|toasts| toasts.retain(|toast| toast.id != id));
+        if prefers_reduced_motion() {
+            remove();
+        } else {
+            set_timeout(move || { remove(); }, TRANSITION);
+        }
+    }
+
+    pub fn dismiss_all(&self) {
+        self.toasts.set(Vec::new());
+    }
+
+    pub fn set_position(&self, position: ToastPosition) {
+        self.position.set(position);
+    }
+
+    /// Mirror the message into the matching screen-reader live region.
+    fn announce(&self, kind: ToastKind, message: &str) {
+        let region = if kind == ToastKind::Error { self.assertive } else { self.polite };
+        region.set(message.to_string());
+    }
+}
+
+/// The [`Toasts`] handle of the enclosing [`ToastProvider`].
+pub fn use_toast() -> Toasts {
+    expect_context::<Toasts>()
+}
+
+/// Provides [`Toasts`] to its children and renders the toast stack.
+#[component]
+pub fn ToastProvider(
+    #[prop(optional)] position: ToastPosition,
+    #[prop(default = 5)] max_visible: usize,
+    children: Children,
+) -> impl IntoView {
+    let toasts = Toasts::new(position, max_visible);
+    provide_context(toasts);
+
+    view! {
+        {children()}
+        <section
+            aria-label="Notifications"
+            class=move || format!("fixed z-[100] flex gap-2 w-[min(24rem,calc(100vw-2rem))] pointer-events-none {}", toasts.position.get().class())
+        >
+            <For
+                each=move || toasts.toasts.get()
+                key=|toast| toast.id
+                children=move |toast| view! { <ToastItem toast=toast toasts=toasts /> }
+            />
+        </section>
+        // Toasts are announced through persistent regions; regions inserted
+        // along with their content are often missed by screen readers.
+        <div class="sr-only" role="status" aria-live="polite" aria-atomic="true">{move || toasts.polite.get()}</div>
+        <div class="sr-only" role="alert" aria-live="assertive" aria-atomic="true">{move || toasts.assertive.get()}</div>
+    }
+}
+
+#[component]
+fn ToastItem(toast: ToastEntry, toasts: Toasts) -> impl IntoView {
+    let ToastEntry { id, kind, message, description, action, duration, leaving } = toast;
+    let entered = RwSignal::new(false);
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+    let remaining = StoredValue::new(None::<f64>);
+    let started = StoredValue::new(0.0);
+
+    let pause = move || {
+        if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+            let elapsed = js_sys::Date::now() - started.get_value();
+            remaining.update_value(|remaining| {
+                if let Some(remaining) = remaining {
+                    *remaining = (*remaining - elapsed).max(0.0);
+                }
+            });
+        }
+    };
+    let resume = move || {
+        pause();
+        if let Some(ms) = remaining.get_value() {
+            started.set_value(js_sys::Date::now());
+            let handle = set_timeout_with_handle(move || toasts.dismiss(id), Duration::from_millis(ms as u64)).ok();
+            timer.set_value(handle);
+        }
+    };
+
+    // (Re)start the countdown on mount and whenever the toast is promoted.
+    Effect::new(move |_| {
+        remaining.set_value(duration.get().map(|duration| duration.as_millis() as f64));
+        timer.update_value(|timer| {
+            if let Some(handle) = timer.take() {
+                handle.clear();
+            }
+        });
+        resume();
+    });
+    on_cleanup(move || {
+        if let Some(Some(handle)) = timer.try_get_value() {
+            handle.clear();
+        }
+    });
+    request_animation_frame(move || {
+        entered.try_set(true);
+    });
+
+    view! {
+        <div
+            class=move || format!(
+                "pointer-events-auto w-full glass-card bg-surface/90 p-4 border-l-4 flex items-start gap-3 transition duration-200 ease-out motion-reduce:transition-none {}",
+                kind.get().class(),
+            )
+            class:opacity-0=move || !entered.get() || leaving.get()
+            class:translate-y-2=move || !entered.get() || leaving.get()
+            on:mouseenter=move |_| pause()
+            on:mouseleave=move |_| resume()
+            on:focusin=move |_| pause()
+            on:focusout=move |_| resume()
+        >
+            <span
+                class=move || format!("shrink-0 w-5 h-5 flex items-center justify-center rounded-full border border-current text-xs font-bold {}", kind.get().icon_class())
+                aria-hidden="true"
+            >
+                {move || {
+                    if kind.get() == ToastKind::Loading {
+                        view! {
+                            <span class="w-3 h-3 rounded-full border-2 border-current border-t-transparent animate-spin motion-reduce:animate-none"></span>
+                        }
+                        .into_any()
+                    } else {
+                        kind.get().icon().into_any()
+                    }
+                }}
+            </span>
+            <div class="flex-1 min-w-0">
+                <p class="text-sm font-inter font-medium text-text-primary">{move || message.get()}</p>
+                {description.map(|description| view! {
+                    <p class="mt-1 text-xs font-inter text-text-secondary">{description}</p>
+                })}
+                {action.map(|ToastAction { label, on_click }| view! {
+                    <button
+                        type="button"
+                        class="btn-text btn-sm mt-2 -ml-3"
+                        on:click=move |_| {
+                            on_click.run(());
+                            toasts.dismiss(id);
+                        }
+                    >
+                        {label}
+                    </button>
+                })}
+            </div>
+            <button
+                type="button"
+                class="shrink-0 w-6 h-6 rounded-full flex items-center justify-center text-text-muted hover:text-accent focus:outline-none focus-visible:ring-2 focus-visible:ring-accent"
+                aria-label="Dismiss notification"
+                on:click=move |_| toasts.dismiss(id)
+            >
+                <span aria-hidden="true">"✕"</span>
+            </button>
+        </div>
+    }
+}
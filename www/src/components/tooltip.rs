@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::html;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+
+use crate::components::focus;
+use crate::components::ids::unique_id;
+use crate::components::position::{use_position, Placement, PositionOptions};
+
+/// Delay before a hovered tooltip shows.
+const SHOW_DELAY: Duration = Duration::from_millis(300);
+/// Grace period for moving the pointer from the anchor onto the tooltip.
+const HIDE_DELAY: Duration = Duration::from_millis(100);
+const ARROW: f64 = 8.0;
+
+/// Short, non-interactive label shown next to its children on hover and
+/// focus.
+///
+/// Hover waits for `delay` (300ms by default); keyboard focus shows it
+/// straight away. The first focusable element inside the children (or the
+/// wrapper itself) gets `aria-describedby` pointing at the tooltip, so screen
+/// readers announce it without it being visible. Escape hides it, and the
+/// pointer can move onto the tooltip without it disappearing.
+#[component]
+pub fn Tooltip(
+    #[prop(into)] content: Signal<String>,
+    #[prop(optional)] placement: Option<Placement>,
+    #[prop(optional)] delay: Option<Duration>,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let id = unique_id("tooltip");
+    let anchor = NodeRef::<html::Span>::new();
+    let floating = NodeRef::<html::Div>::new();
+    let open = RwSignal::new(false);
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+
+    let options = PositionOptions::new(placement.unwrap_or(Placement::TOP)).arrow(ARROW);
+    let position = use_position(move || anchor.get_untracked().map(Into::into), floating, open.into(), options);
+
+    // Describe the element that actually takes focus, not the wrapper.
+    let target_id = id.clone();
+    Effect::new(move |_| {
+        let Some(wrapper) = anchor.get() else {
+            return;
+        };
+        let target = focus::first_focusable(&wrapper).unwrap_or_else(|| wrapper.into());
+        let existing = target.get_attribute("aria-describedby").unwrap_or_default();
+        if !existing.split_whitespace().any(|token| token == target_id) {
+            let value = format!("{} {}", existing, target_id);
+            let _ = target.set_attribute("aria-describedby", value.trim());
+        }
+    });
+
+    let clear_timer = move || {
+        if let Some(Some(handle)) = timer.try_update_value(Option::take) {
+            handle.clear();
+        }
+    };
+    let set_open_after = move |value: bool, wait: Duration| {
+        clear_timer();
+        if open.get_untracked() == value {
+            return;
+        }
+        let handle = set_timeout_with_handle(
+            move || {
+                open.try_set(value);
+            },
+            wait,
+        );
+        timer.set_value(handle.ok());
+    };
+    let show_now = move || {
+        clear_timer();
+        open.set(true);
+    };
+    let hide_now = move || {
+        clear_timer();
+        open.set(false);
+    };
+
+    on_cleanup(clear_timer);
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() == "Escape" && open.get_untracked() {
+            ev.stop_propagation();
+            hide_now();
+        }
+    };
+
+    let tooltip_class = format!(
+        "fixed z-[80] max-w-xs px-2 py-1 rounded-md pointer-events-auto bg-surface-elevated border border-border text-xs font-inter text-text-primary shadow-[0_0_8px_1px_theme(colors.glow)] transition-opacity duration-150 motion-reduce:transition-none {}",
+        class.unwrap_or_default()
+    );
+
+    view! {
+        <span
+            node_ref=anchor
+            class="inline-flex"
+            on:mouseenter=move |_| set_open_after(true, delay.unwrap_or(SHOW_DELAY))
+            on:mouseleave=move |_| set_open_after(false, HIDE_DELAY)
+            on:focusin=move |_| show_now()
+            on:focusout=move |_| hide_now()
+            on:keydown=on_keydown
+        >
+            {children()}
+        </span>
+        <Portal>
+            <div
+                node_ref=floating
+                id=id.clone()
+                role="tooltip"
+                class=tooltip_class.clone()
+                class:hidden=move || !open.get()
+                class:opacity-0=move || position.get().is_none()
+                style=move || position.get().map(|position| position.style()).unwrap_or_default()
+                on:mouseenter=move |_| clear_timer()
+                on:mouseleave=move |_| set_open_after(false, HIDE_DELAY)
+            >
+                {move || content.get()}
+                <span
+                    class=move || format!(
+                        "absolute w-2 h-2 rotate-45 bg-surface-elevated border-border {}",
+                        position.get().map(|position| position.placement.side.arrow_border()).unwrap_or_default()
+                    )
+                    style=move || position.get().map(|position| position.arrow_style()).unwrap_or_default()
+                    aria-hidden="true"
+                ></span>
+            </div>
+        </Portal>
+    }
+}
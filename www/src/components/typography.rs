@@ -3,159 +3,248 @@ use leptos::prelude::*;
 use leptos::html::ElementChild;
 use leptos::attr::global::ClassAttribute;
 
-// Heading Components
-#[component]
-pub fn Heading1(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("text-4xl font-orbitron font-bold tracking-orbitron {}", class.unwrap_or_default());
-    
-    view! {
-        <h1 class={class_value}>
-            {children()}
-        </h1>
+/// Heading levels, used both for the visual scale and the semantic tag.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum HeadingLevel {
+    H1,
+    #[default]
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+}
+
+impl HeadingLevel {
+    pub fn class(&self) -> &'static str {
+        match self {
+            HeadingLevel::H1 => "text-4xl font-orbitron font-bold tracking-orbitron",
+            HeadingLevel::H2 => "text-3xl font-orbitron font-bold tracking-wide",
+            HeadingLevel::H3 => "text-2xl font-outfit font-semibold",
+            HeadingLevel::H4 => "text-xl font-outfit font-semibold",
+            HeadingLevel::H5 => "text-lg font-outfit font-semibold",
+            HeadingLevel::H6 => "text-base font-outfit font-semibold",
+        }
     }
 }
 
+/// Heading whose look (`level`) is independent of its tag (`as_tag`), so a
+/// visual H2 can sit at h3 in the document outline. The tag defaults to the
+/// visual level.
 #[component]
-pub fn Heading2(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
+pub fn Heading(
+    #[prop(optional)] level: HeadingLevel,
+    #[prop(optional)] as_tag: Option<HeadingLevel>,
+    #[prop(optional, into)] class: Signal<String>,
+    #[prop(optional, into)] id: Option<String>,
+    children: Children,
 ) -> impl IntoView {
-    let class_value = format!("text-3xl font-orbitron font-bold tracking-wide {}", class.unwrap_or_default());
-    
-    view! {
-        <h2 class={class_value}>
-            {children()}
-        </h2>
+    let class_value = move || format!("{} {}", level.class(), class.get());
+
+    match as_tag.unwrap_or(level) {
+        HeadingLevel::H1 => view! { <h1 id=id class=class_value>{children()}</h1> }.into_any(),
+        HeadingLevel::H2 => view! { <h2 id=id class=class_value>{children()}</h2> }.into_any(),
+        HeadingLevel::H3 => view! { <h3 id=id class=class_value>{children()}</h3> }.into_any(),
+        HeadingLevel::H4 => view! { <h4 id=id class=class_value>{children()}</h4> }.into_any(),
+        HeadingLevel::H5 => view! { <h5 id=id class=class_value>{children()}</h5> }.into_any(),
+        HeadingLevel::H6 => view! { <h6 id=id class=class_value>{children()}</h6> }.into_any(),
     }
 }
 
-#[component]
-pub fn Heading3(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("text-2xl font-outfit font-semibold {}", class.unwrap_or_default());
-    
-    view! {
-        <h3 class={class_value}>
-            {children()}
-        </h3>
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextSize {
+    Xs,
+    Sm,
+    #[default]
+    Base,
+    Lg,
+    Xl,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextWeight {
+    /// Inherit the surrounding weight.
+    #[default]
+    Inherit,
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextTone {
+    /// Inherit the surrounding color.
+    #[default]
+    Inherit,
+    Primary,
+    Secondary,
+    Muted,
+    Accent,
+    Success,
+    Warning,
+    Error,
+}
+
+/// Element a [`Text`] renders as.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TextTag {
+    #[default]
+    P,
+    Span,
+    Div,
+    Small,
+    Strong,
+    Em,
+}
+
+impl TextSize {
+    pub fn class(&self) -> &'static str {
+        match self {
+            TextSize::Xs => "text-xs",
+            TextSize::Sm => "text-sm",
+            TextSize::Base => "text-base",
+            TextSize::Lg => "text-lg",
+            TextSize::Xl => "text-xl",
+        }
     }
 }
 
-#[component]
-pub fn Heading4(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("text-xl font-outfit font-semibold {}", class.unwrap_or_default());
-    
-    view! {
-        <h4 class={class_value}>
-            {children()}
-        </h4>
+impl TextWeight {
+    pub fn class(&self) -> &'static str {
+        match self {
+            TextWeight::Inherit => "",
+            TextWeight::Normal => "font-normal",
+            TextWeight::Medium => "font-medium",
+            TextWeight::Semibold => "font-semibold",
+            TextWeight::Bold => "font-bold",
+        }
     }
 }
 
-#[component]
-pub fn Heading5(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("text-lg font-outfit font-semibold {}", class.unwrap_or_default());
-    
-    view! {
-        <h5 class={class_value}>
-            {children()}
-        </h5>
+impl TextTone {
+    pub fn class(&self) -> &'static str {
+        match self {
+            TextTone::Inherit => "",
+            TextTone::Primary => "text-text-primary",
+            TextTone::Secondary => "text-text-secondary",
+            TextTone::Muted => "text-text-muted",
+            TextTone::Accent => "text-accent",
+            TextTone::Success => "text-success",
+            TextTone::Warning => "text-warning",
+            TextTone::Error => "text-error",
+        }
     }
 }
 
-// Body Text Components
-#[component]
-pub fn BodyLarge(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("text-xl font-inter {}", class.unwrap_or_default());
-    
-    view! {
-        <p class={class_value}>
-            {children()}
-        </p>
+/// Tailwind class clamping text to `lines` lines (1-6); spelled out so the
+/// class names survive Tailwind's source scan.
+fn line_clamp_class(lines: u8) -> &'static str {
+    match lines {
+        0 => "",
+        1 => "line-clamp-1",
+        2 => "line-clamp-2",
+        3 => "line-clamp-3",
+        4 => "line-clamp-4",
+        5 => "line-clamp-5",
+        _ => "line-clamp-6",
     }
 }
 
+/// Body text with size, weight and tone options. `truncate` cuts a single
+/// line with an ellipsis; `line_clamp` does the same after N lines.
 #[component]
-pub fn Body(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
+pub fn Text(
+    #[prop(into, optional)] size: Signal<TextSize>,
+    #[prop(into, optional)] weight: Signal<TextWeight>,
+    #[prop(into, optional)] tone: Signal<TextTone>,
+    #[prop(optional)] as_tag: TextTag,
+    #[prop(into, optional)] truncate: Signal<bool>,
+    #[prop(into, optional)] line_clamp: Signal<u8>,
+    #[prop(optional, into)] class: Signal<String>,
+    children: Children,
 ) -> impl IntoView {
-    let class_value = format!("text-base font-inter {}", class.unwrap_or_default());
-    
-    view! {
-        <p class={class_value}>
-            {children()}
-        </p>
+    let class_value = move || {
+        format!(
+            "{} font-inter {} {} {} {} {}",
+            size.get().class(),
+            weight.get().class(),
+            tone.get().class(),
+            if truncate.get() { "truncate" } else { "" },
+            line_clamp_class(line_clamp.get()),
+            class.get()
+        )
+    };
+
+    match as_tag {
+        TextTag::P => view! { <p class=class_value>{children()}</p> }.into_any(),
+        TextTag::Span => view! { <span class=class_value>{children()}</span> }.into_any(),
+        TextTag::Div => view! { <div class=class_value>{children()}</div> }.into_any(),
+        TextTag::Small => view! { <small class=class_value>{children()}</small> }.into_any(),
+        TextTag::Strong => view! { <strong class=class_value>{children()}</strong> }.into_any(),
+        TextTag::Em => view! { <em class=class_value>{children()}</em> }.into_any(),
     }
 }
 
+// Fixed-level wrappers kept for existing pages.
 #[component]
-pub fn BodySmall(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("text-sm font-inter {}", class.unwrap_or_default());
-    
-    view! {
-        <p class={class_value}>
-            {children()}
-        </p>
-    }
+pub fn Heading1(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Heading level=HeadingLevel::H1 class=class.unwrap_or_default()>{children()}</Heading> }
 }
 
 #[component]
-pub fn Caption(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("text-xs font-inter text-text-secondary {}", class.unwrap_or_default());
-    
-    view! {
-        <p class={class_value}>
-            {children()}
-        </p>
-    }
+pub fn Heading2(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Heading level=HeadingLevel::H2 class=class.unwrap_or_default()>{children()}</Heading> }
 }
 
-// Code Display Components
 #[component]
-pub fn CodeBlock(
-    #[prop(into)] code: String,
-    #[prop(optional)] language: Option<String>,
-    #[prop(optional)] class: Option<String>,
-) -> impl IntoView {
-    let class_value = format!("p-4 rounded-md bg-surface-elevated overflow-x-auto {}", class.unwrap_or_default());
-    let code_class = format!("font-mono text-sm {}", language.unwrap_or_default());
-    
+pub fn Heading3(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Heading level=HeadingLevel::H3 class=class.unwrap_or_default()>{children()}</Heading> }
+}
+
+#[component]
+pub fn Heading4(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Heading level=HeadingLevel::H4 class=class.unwrap_or_default()>{children()}</Heading> }
+}
+
+#[component]
+pub fn Heading5(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Heading level=HeadingLevel::H5 class=class.unwrap_or_default()>{children()}</Heading> }
+}
+
+#[component]
+pub fn BodyLarge(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Text size=TextSize::Xl class=class.unwrap_or_default()>{children()}</Text> }
+}
+
+#[component]
+pub fn Body(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Text class=class.unwrap_or_default()>{children()}</Text> }
+}
+
+#[component]
+pub fn BodySmall(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    view! { <Text size=TextSize::Sm class=class.unwrap_or_default()>{children()}</Text> }
+}
+
+#[component]
+pub fn Caption(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
     view! {
-        <pre class={class_value}>
-            <code class={code_class}>
-                {code}
-            </code>
-        </pre>
+        <Text size=TextSize::Xs tone=TextTone::Secondary class=class.unwrap_or_default()>
+            {children()}
+        </Text>
     }
 }
 
+/// Classes of `InlineCode`, for markup rendered as an HTML string.
+pub(crate) const INLINE_CODE_CLASS: &str = "px-1.5 py-0.5 rounded font-mono text-sm bg-surface";
+
 #[component]
 pub fn InlineCode(
-    #[prop(into)] children: Children,
-    #[prop(optional)] class: Option<String>,
+    children: Children,
+    #[prop(optional, into)] class: Option<String>,
 ) -> impl IntoView {
-    let class_value = format!("px-1.5 py-0.5 rounded font-mono text-sm bg-surface {}", class.unwrap_or_default());
+    let class_value = format!("{} {}", INLINE_CODE_CLASS, class.unwrap_or_default());
     
     view! {
         <code class={class_value}>
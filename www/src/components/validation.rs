@@ -0,0 +1,258 @@
+//! Field validators used by [`FormState`](crate::components::form::FormState).
+//!
+//! Validators are plain functions from a value to `Result<(), String>` and
+//! don't touch the DOM or the reactive runtime, so they can be exercised
+//! directly in unit tests. Apart from `required`, they accept empty values;
+//! combine them with `required` when a value must be present.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use regex::Regex;
+use regex_automata::meta;
+use regex_syntax::hir::{Hir, Look};
+
+/// Values that can be "missing" for the purposes of [`Validator::required`].
+pub trait Emptiable {
+    fn is_empty_value(&self) -> bool;
+}
+
+impl Emptiable for String {
+    fn is_empty_value(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl Emptiable for bool {
+    /// An unchecked box counts as empty, so `required` works for consent
+    /// checkboxes.
+    fn is_empty_value(&self) -> bool {
+        !*self
+    }
+}
+
+impl<T> Emptiable for Option<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T> Emptiable for Vec<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Why `email` isn't a plausible address, with a message for the user.
+pub fn validate_email(email: &str) -> Result<(), &'static str> {
+    let email = email.trim();
+    if email.is_empty() {
+        return Err("Please enter your email address.");
+    }
+
+    let valid = email.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && !domain.starts_with('.')
+            && !domain.ends_with('.')
+            && domain.contains('.')
+            && !email.contains(char::is_whitespace)
+            && !domain.contains('@')
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err("Please enter a valid email address.")
+    }
+}
+
+type CheckFn<T> = Arc<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+/// A synchronous check on a field value.
+pub struct Validator<T> {
+    check: CheckFn<T>,
+    message: Option<String>,
+}
+
+impl<T> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        Self { check: self.check.clone(), message: self.message.clone() }
+    }
+}
+
+impl<T> Validator<T> {
+    /// A custom validator.
+    pub fn new(check: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self { check: Arc::new(check), message: None }
+    }
+
+    /// Replace the validator's error message.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn validate(&self, value: &T) -> Result<(), String> {
+        (self.check)(value).map_err(|err| self.message.clone().unwrap_or(err))
+    }
+}
+
+impl<T: Emptiable> Validator<T> {
+    pub fn required() -> Self {
+        Self::new(|value: &T| {
+            if value.is_empty_value() {
+                Err("This field is required.".to_string())
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+impl Validator<String> {
+    pub fn email() -> Self {
+        Self::new(|value: &String| {
+            if value.trim().is_empty() {
+                return Ok(());
+            }
+            validate_email(value).map_err(str::to_string)
+        })
+    }
+
+    /// At least `min` characters.
+    pub fn min_length(min: usize) -> Self {
+        Self::new(move |value: &String| {
+            let len = value.trim().chars().count();
+            if len == 0 || len >= min {
+                Ok(())
+            } else {
+                Err(format!("Must be at least {min} characters."))
+            }
+        })
+    }
+
+    /// At most `max` characters.
+    pub fn max_length(max: usize) -> Self {
+        Self::new(move |value: &String| {
+            if value.trim().chars().count() <= max {
+                Ok(())
+            } else {
+                Err(format!("Must be at most {max} characters."))
+            }
+        })
+    }
+
+    /// The whole value must match `pattern`, as if it were anchored with `^...$`.
+    pub fn pattern(pattern: Regex, message: impl Into<String>) -> Self {
+        let message = message.into();
+        // Anchor the parsed pattern rather than its source: pasting `^(?:…)$`
+        // around a `(?x)` pattern ending in a `#` comment would comment out
+        // the closing anchor.
+        let hir = regex_syntax::parse(pattern.as_str()).expect("`pattern` already compiled");
+        let anchored = Hir::concat(vec![Hir::look(Look::Start), hir, Hir::look(Look::End)]);
+        let pattern = meta::Regex::builder().build_from_hir(&anchored).expect("`pattern` already compiled");
+        Self::new(move |value: &String| {
+            if value.is_empty() || pattern.is_match(value) {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        })
+    }
+}
+
+/// Future returned by an [`AsyncValidator`]. Not `Send`: in the browser it
+/// usually awaits a `fetch`.
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// A check that needs the server, e.g. "is this handle taken?". Runs on blur
+/// and before submit, after the synchronous validators pass.
+pub struct AsyncValidator<T> {
+    check: Arc<dyn Fn(T) -> ValidationFuture + Send + Sync>,
+}
+
+impl<T> Clone for AsyncValidator<T> {
+    fn clone(&self) -> Self {
+        Self { check: self.check.clone() }
+    }
+}
+
+impl<T> AsyncValidator<T> {
+    pub fn new<F, Fut>(check: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self { check: Arc::new(move |value| Box::pin(check(value))) }
+    }
+
+    pub fn validate(&self, value: T) -> ValidationFuture {
+        (self.check)(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(validator: &Validator<String>, value: &str) -> Result<(), String> {
+        validator.validate(&value.to_string())
+    }
+
+    #[test]
+    fn required_rejects_blank_values() {
+        let required = Validator::<String>::required();
+        assert!(check(&required, "").is_err());
+        assert!(check(&required, "   ").is_err());
+        assert!(check(&required, "x").is_ok());
+        assert!(Validator::<bool>::required().validate(&false).is_err());
+        assert!(Validator::<Option<u8>>::required().validate(&Some(0)).is_ok());
+    }
+
+    #[test]
+    fn email_accepts_empty_and_plausible_addresses() {
+        let email = Validator::email();
+        assert!(check(&email, "").is_ok());
+        assert!(check(&email, " ada@example.com ").is_ok());
+        for invalid in ["ada", "@example.com", "ada@example", "ada@.com", "ada@example.", "a da@example.com", "a@b@c.com"] {
+            assert!(check(&email, invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn min_length_counts_trimmed_characters() {
+        let min = Validator::min_length(3);
+        assert!(check(&min, "").is_ok());
+        assert!(check(&min, " ab ").is_err());
+        assert!(check(&min, "abc").is_ok());
+        assert!(check(&min, "äöü").is_ok());
+    }
+
+    #[test]
+    fn max_length_counts_trimmed_characters() {
+        let max = Validator::max_length(3);
+        assert!(check(&max, "abc ").is_ok());
+        assert!(check(&max, "äöü").is_ok());
+        assert!(check(&max, "abcd").is_err());
+    }
+
+    #[test]
+    fn pattern_must_match_the_whole_value() {
+        let digits = Validator::pattern(Regex::new(r"\d+").unwrap(), "Digits only.");
+        assert!(check(&digits, "").is_ok());
+        assert!(check(&digits, "123").is_ok());
+        assert_eq!(check(&digits, "12a"), Err("Digits only.".to_string()));
+        let either = Validator::pattern(Regex::new("a|ab").unwrap(), "No.");
+        assert!(check(&either, "ab").is_ok());
+        let commented = Validator::pattern(Regex::new("(?x) [a-z]+ # letters").unwrap(), "Letters only.");
+        assert!(check(&commented, "abc").is_ok());
+        assert!(check(&commented, "abc1").is_err());
+    }
+
+    #[test]
+    fn message_replaces_the_default_error() {
+        let required = Validator::<String>::required().message("Name, please.");
+        assert_eq!(check(&required, ""), Err("Name, please.".to_string()));
+    }
+}
@@ -0,0 +1,222 @@
+//! Error reporting and the crash screen.
+//!
+//! Errors caught by `components::ErrorBoundary` and panics both become an
+//! [`ErrorReport`] handed to the installed reporter: the console by default,
+//! or anything passed to [`set_reporter`], such as [`beacon_reporter`].
+//!
+//! A panic leaves the wasm instance unusable, so the handler installed by
+//! [`install_panic_handler`] also replaces the page with a static crash
+//! screen. Its reload button is plain HTML and doesn't call into wasm.
+
+use std::panic::PanicHookInfo;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReportKind {
+    /// The app crashed.
+    Panic,
+    /// A view failed but the app kept running.
+    Error,
+}
+
+impl ReportKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportKind::Panic => "panic",
+            ReportKind::Error => "error",
+        }
+    }
+}
+
+/// What went wrong, where, and in which page and browser.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorReport {
+    pub kind: ReportKind,
+    pub message: String,
+    /// `file:line:column` of a panic.
+    pub location: Option<String>,
+    pub url: String,
+    pub user_agent: String,
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: f64,
+}
+
+impl ErrorReport {
+    pub fn new(kind: ReportKind, message: impl Into<String>) -> Self {
+        let window = web_sys::window();
+        Self {
+            kind,
+            message: message.into(),
+            location: None,
+            url: window.as_ref().and_then(|window| window.location().href().ok()).unwrap_or_default(),
+            user_agent: window.and_then(|window| window.navigator().user_agent().ok()).unwrap_or_default(),
+            timestamp: js_sys::Date::now(),
+        }
+    }
+
+    fn from_panic(info: &PanicHookInfo) -> Self {
+        let mut report = Self::new(ReportKind::Panic, info.payload_as_str().unwrap_or("panic with a non-string payload"));
+        report.location =
+            info.location().map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column()));
+        report
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"kind\":{},\"message\":{},\"location\":{},\"url\":{},\"userAgent\":{},\"timestamp\":{}}}",
+            json_string(self.kind.as_str()),
+            json_string(&self.message),
+            self.location.as_deref().map_or("null".to_string(), json_string),
+            json_string(&self.url),
+            json_string(&self.user_agent),
+            self.timestamp,
+        )
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+type Reporter = Arc<dyn Fn(&ErrorReport) + Send + Sync>;
+
+static REPORTER: RwLock<Option<Reporter>> = RwLock::new(None);
+static CRASHED: AtomicBool = AtomicBool::new(false);
+
+/// Send every report to `reporter` instead of the console.
+pub fn set_reporter(reporter: impl Fn(&ErrorReport) + Send + Sync + 'static) {
+    if let Ok(mut current) = REPORTER.write() {
+        *current = Some(Arc::new(reporter));
+    }
+}
+
+/// Hand `report` to the installed reporter.
+pub fn report(report: &ErrorReport) {
+    let reporter = REPORTER.try_read().ok().and_then(|reporter| reporter.clone());
+    match reporter {
+        Some(reporter) => reporter(report),
+        None => console_reporter(report),
+    }
+}
+
+/// The default reporter: logs the report as an error.
+pub fn console_reporter(report: &ErrorReport) {
+    log::error!("[{}] {}", report.kind.as_str(), report.to_json());
+}
+
+/// A reporter POSTing each report as JSON to `url` with
+/// `navigator.sendBeacon`, which still delivers while the page is crashing
+/// or being unloaded.
+pub fn beacon_reporter(url: impl Into<String>) -> impl Fn(&ErrorReport) + Send + Sync + 'static {
+    let url = url.into();
+    move |report| {
+        let sent = web_sys::window()
+            .and_then(|window| window.navigator().send_beacon_with_opt_str(&url, Some(&report.to_json())).ok());
+        if sent != Some(true) {
+            console_reporter(report);
+        }
+    }
+}
+
+/// Report panics and show the crash screen. Call once, before mounting.
+pub fn install_panic_handler() {
+    std::panic::set_hook(Box::new(|info| {
+        // Keeps the stack trace in the console.
+        console_error_panic_hook::hook(info);
+        if CRASHED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let crash = ErrorReport::from_panic(info);
+        report(&crash);
+        show_crash_screen(&crash);
+    }));
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Replace the page with a static explanation and a reload button.
+fn show_crash_screen(report: &ErrorReport) {
+    let Some(body) = web_sys::window().and_then(|window| window.document()).and_then(|document| document.body()) else {
+        return;
+    };
+    let details = match &report.location {
+        Some(location) => format!("{}\n  at {}", report.message, location),
+        None => report.message.clone(),
+    };
+    body.set_inner_html(&format!(
+        r#"<div class="min-h-screen flex items-center justify-center bg-background p-6">
+  <div class="glass-card max-w-lg w-full text-center" role="alert">
+    <h1 class="text-2xl font-orbitron font-bold text-error mb-2">Something broke</h1>
+    <p class="font-inter text-text-secondary mb-6">The app hit an unexpected error and had to stop. Reloading usually fixes it.</p>
+    <button type="button" class="btn-primary" onclick="location.reload()">Reload</button>
+    <details class="mt-6 text-left">
+      <summary class="cursor-pointer text-sm font-inter text-text-muted">Details</summary>
+      <pre class="mt-2 p-3 rounded bg-surface-elevated text-xs font-mono text-text-secondary whitespace-pre-wrap break-words">{}</pre>
+    </details>
+  </div>
+</div>"#,
+        escape_html(&details)
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_string_escapes_quotes_and_backslashes() {
+        assert_eq!(json_string(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(json_string(r"C:\temp\"), r#""C:\\temp\\""#);
+    }
+
+    #[test]
+    fn json_string_escapes_control_characters() {
+        assert_eq!(json_string("a\nb\r\tc"), r#""a\nb\r\tc""#);
+        assert_eq!(json_string("\u{0}\u{1b}\u{1f}"), r#""\u0000\u001b\u001f""#);
+        // DEL and above are valid unescaped.
+        assert_eq!(json_string("\u{7f}"), "\"\u{7f}\"");
+    }
+
+    #[test]
+    fn json_string_keeps_non_ascii_as_is() {
+        assert_eq!(json_string("café"), "\"café\"");
+        assert_eq!(json_string("🦀 \u{10ffff}"), "\"🦀 \u{10ffff}\"");
+    }
+
+    #[test]
+    fn to_json_writes_every_field() {
+        let report = ErrorReport {
+            kind: ReportKind::Panic,
+            message: "index out of bounds: \"len\" is 0".to_string(),
+            location: Some("src/lib.rs:1:2".to_string()),
+            url: "https://example.com/?q=\\".to_string(),
+            user_agent: "Test/1.0".to_string(),
+            timestamp: 1700000000000.0,
+        };
+        assert_eq!(
+            report.to_json(),
+            r#"{"kind":"panic","message":"index out of bounds: \"len\" is 0","location":"src/lib.rs:1:2","url":"https://example.com/?q=\\","userAgent":"Test/1.0","timestamp":1700000000000}"#
+        );
+
+        let report = ErrorReport { kind: ReportKind::Error, location: None, ..report };
+        assert!(report.to_json().starts_with(r#"{"kind":"error","#));
+        assert!(report.to_json().contains(r#""location":null,"#));
+    }
+}
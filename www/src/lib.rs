@@ -1,61 +1,106 @@
 use leptos::*;
 use leptos::prelude::*;
 use leptos_router::components::{A, Route, Router, Routes};
+use leptos_router::hooks::use_location;
 use leptos_router::path;
 
 // Modules
-mod components;
+pub mod chunks;
+pub mod components;
+pub mod errors;
 mod pages;
+pub mod redirects;
+
+// The icon clean-up `build.rs` runs, included here for its tests.
+#[cfg(test)]
+#[path = "../build/svg.rs"]
+mod build_svg;
 
 // Import components and pages
+use crate::chunks::{ChunkLink, ChunkProvider};
 use crate::components::*;
+use crate::components::ErrorBoundary;
 use crate::pages::home::Home;
 use crate::pages::component_showcase::ComponentShowcase;
-use crate::pages::not_found::NotFound;
+use crate::pages::not_found::RouteFallback;
+use crate::pages::technology::Technology;
+
+/// Pages listed in the command palette.
+fn site_commands() -> Vec<Command> {
+    vec![
+        Command::navigate("page.home", "Home", "/").keywords(["start", "landing"]),
+        Command::navigate("page.showcase", "Components", "/showcase").keywords(["showcase", "design system", "ui"]),
+        Command::navigate("page.technology", "Technology", "/technology").keywords(["stack", "rust", "graph"]),
+    ]
+}
+
+/// The routed page, in an error boundary that resets when the path changes.
+/// Paths added here go in `redirects::ROUTES` too.
+#[component]
+fn AppRoutes() -> impl IntoView {
+    let location = use_location();
+
+    view! {
+        <ErrorBoundary reset_key=location.pathname>
+            <Routes fallback=|| view! { <RouteFallback/> }>
+                <Route path=path!("/") view=|| view! { <Home/> }/>
+                <Route path=path!("/showcase") view=|| view! { <ComponentShowcase/> }/>
+                <Route path=path!("/technology") view=|| view! { <Technology/> }/>
+            </Routes>
+        </ErrorBoundary>
+    }
+}
 
 /// Main app component
 #[component]
 pub fn App() -> impl IntoView {
     view! {
         <ThemeProvider>
-            <Router>
-                <div class="min-h-screen flex flex-col">
-                    <header class="bg-base0D/10 shadow-sm border-b border-base03/20">
-                        <div class="container mx-auto p-4 flex justify-between items-center">
-                            <h1 class="text-2xl font-bold text-base0D">
-                                <A href="/">"W3 Memory Bank"</A>
-                            </h1>
-                            <nav class="flex space-x-4">
-                                <A 
-                                    href="/" 
-                                    attr:class="text-base05 hover:text-base0D px-3 py-2 rounded-md hover:bg-base01/50 transition-colors"
-                                >
-                                    "Home"
-                                </A>
-                                <A 
-                                    href="/showcase" 
-                                    attr:class="text-base05 hover:text-base0D px-3 py-2 rounded-md hover:bg-base01/50 transition-colors"
-                                >
-                                    "Components"
-                                </A>
-                            </nav>
-                        </div>
-                    </header>
-                    
-                    <main class="flex-grow container mx-auto p-6">
-                        <Routes fallback=|| view! { <NotFound/> }>
-                            <Route path=path!("/") view=|| view! { <Home/> }/>
-                            <Route path=path!("/showcase") view=|| view! { <ComponentShowcase/> }/>
-                        </Routes>
-                    </main>
-                    
-                    <footer class="bg-base01 text-base05">
-                        <div class="container mx-auto p-4 text-center">
-                            <p>"W3 Memory Bank © 2023"</p>
-                        </div>
-                    </footer>
-                </div>
-            </Router>
+            <ChunkProvider>
+                <ToastProvider>
+                    <Router>
+                        <CommandProvider commands=site_commands()>
+                            <div class="min-h-screen flex flex-col">
+                                <header class="bg-base0D/10 shadow-sm border-b border-base03/20">
+                                    <div class="container mx-auto p-4 flex justify-between items-center">
+                                        <h1 class="text-2xl font-bold text-base0D">
+                                            <A href="/">"W3 Memory Bank"</A>
+                                        </h1>
+                                        <nav class="flex space-x-4">
+                                            <A 
+                                                href="/" 
+                                                attr:class="text-base05 hover:text-base0D px-3 py-2 rounded-md hover:bg-base01/50 transition-colors"
+                                            >
+                                                "Home"
+                                            </A>
+                                            <A 
+                                                href="/showcase" 
+                                                attr:class="text-base05 hover:text-base0D px-3 py-2 rounded-md hover:bg-base01/50 transition-colors"
+                                            >
+                                                "Components"
+                                            </A>
+                                            <ChunkLink
+                                                href="/technology"
+                                                chunk="technology"
+                                                class="text-base05 hover:text-base0D px-3 py-2 rounded-md hover:bg-base01/50 transition-colors"
+                                            >
+                                                "Technology"
+                                            </ChunkLink>
+                                            <CommandPaletteButton class="hidden sm:flex" />
+                                        </nav>
+                                    </div>
+                                </header>
+                        
+                                <main class="flex-grow container mx-auto p-6">
+                                    <AppRoutes />
+                                </main>
+                        
+                                <Footer />
+                            </div>
+                        </CommandProvider>
+                    </Router>
+                </ToastProvider>
+            </ChunkProvider>
         </ThemeProvider>
     }
 }
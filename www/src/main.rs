@@ -4,7 +4,7 @@ use www::App;
 fn main() {
     // set up logging
     _ = console_log::init_with_level(log::Level::Debug);
-    console_error_panic_hook::set_once();
+    www::errors::install_panic_handler();
 
     mount_to_body(|| {
         view! {
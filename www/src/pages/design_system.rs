@@ -2,10 +2,13 @@ use leptos::*;
 use leptos::prelude::*;
 use leptos::html::ElementChild;
 use crate::components::*;
+use crate::pages::stories;
 
 /// Design System Page - Fixed for Leptos 0.7.8
 #[component]
 pub fn DesignSystem() -> impl IntoView {
+    let registry = stories::registry();
+
     html::div()
         .class("min-h-screen bg-background")
         .child((
@@ -51,7 +54,7 @@ pub fn DesignSystem() -> impl IntoView {
                                             html::h2()
                                                 .class("text-3xl font-orbitron font-bold tracking-wide mb-8")
                                                 .child("Typography"),
-                                            "Typography Showcase (Builder-based implementation)"
+                                            story_gallery_section(registry.clone(), "Heading")
                                         )),
                                     html::section()
                                         .attr("id", "buttons")
@@ -59,7 +62,7 @@ pub fn DesignSystem() -> impl IntoView {
                                             html::h2()
                                                 .class("text-3xl font-orbitron font-bold tracking-wide mb-8")
                                                 .child("Buttons"),
-                                            "Button Showcase (Builder-based implementation)"
+                                            story_gallery_section(registry.clone(), "Button")
                                         )),
                                     html::section()
                                         .attr("id", "cards")
@@ -67,7 +70,7 @@ pub fn DesignSystem() -> impl IntoView {
                                             html::h2()
                                                 .class("text-3xl font-orbitron font-bold tracking-wide mb-8")
                                                 .child("Cards"),
-                                            "Card Showcase (Builder-based implementation)"
+                                            story_gallery_section(registry.clone(), "Card")
                                         )),
                                     html::section()
                                         .attr("id", "colors")
@@ -94,6 +97,15 @@ pub fn DesignSystem() -> impl IntoView {
         ))
 }
 
+/// The stories of `component`, at their initial props.
+fn story_gallery_section(registry: StoryRegistry, component: &'static str) -> impl IntoView {
+    view! {
+        <div class="grid gap-6 sm:grid-cols-2">
+            <StoryGallery registry=registry component=component />
+        </div>
+    }
+}
+
 /// Simplified color showcase that doesn't rely on our custom components
 #[component]
 fn ColorShowcaseSimple() -> impl IntoView {
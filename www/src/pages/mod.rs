@@ -2,3 +2,5 @@ pub mod home;
 pub mod design_system;
 pub mod not_found;
 pub mod component_showcase;
+pub mod stories;
+pub mod technology;
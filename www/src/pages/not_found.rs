@@ -2,6 +2,45 @@ use leptos::*;
 use leptos::prelude::*;
 use leptos::html::ElementChild;
 use crate::components::*;
+use crate::redirects;
+use leptos_router::components::Redirect;
+use leptos_router::hooks::use_location;
+use leptos_router::NavigateOptions;
+
+/// Router fallback: sends legacy URLs listed in the redirect table to their new
+/// route, and renders `NotFound` for everything else.
+#[component]
+pub fn RouteFallback() -> impl IntoView {
+    let location = use_location();
+
+    move || {
+        let mut path = location.pathname.get();
+        let search = location.search.get();
+        if !search.is_empty() {
+            path.push('?');
+            path.push_str(&search);
+        }
+        let hash = location.hash.get();
+        if !hash.is_empty() && !hash.starts_with('#') {
+            path.push('#');
+        }
+        path.push_str(&hash);
+
+        match redirects::resolve(&path) {
+            Some(redirect) => {
+                log::debug!("redirecting legacy URL {} -> {} ({:?})", path, redirect.target, redirect.kind);
+                view! {
+                    <Redirect
+                        path=redirect.target
+                        options=NavigateOptions { replace: true, ..Default::default() }
+                    />
+                }
+                .into_any()
+            }
+            None => NotFound().into_any(),
+        }
+    }
+}
 
 /// 404 Page Not Found component - Fixed for Leptos 0.7.8
 #[component]
@@ -0,0 +1,303 @@
+//! Stories shown by `ComponentShowcase` and `DesignSystem`.
+
+use leptos::prelude::*;
+
+use crate::components::storybook::story_enum;
+use crate::components::*;
+use crate::components::typography::{Heading, HeadingLevel, Text, TextSize, TextTone};
+
+story_enum!(ButtonVariant { Primary, Secondary, Text });
+story_enum!(ButtonSize { Small, Medium, Large });
+story_enum!(CardVariant { Basic, Elevated, Glass, GlowEdge });
+story_enum!(HeadingLevel { H1, H2, H3, H4, H5, H6 });
+story_enum!(TextSize { Xs, Sm, Base, Lg, Xl });
+story_enum!(TextTone { Inherit, Primary, Secondary, Muted, Accent, Success, Warning, Error });
+story_enum!(IconName { Chat, Command, Contact, Dashboard, Events, GitHub, GraphQl, Menu, People, Science, Settings, Tools, Workflow }, required);
+story_enum!(IconSize { Inherit, ExtraSmall, Small, Medium, Large, ExtraLarge });
+story_enum!(SpinnerSize { Small, Medium, Large });
+story_enum!(SkeletonShape { Text, Rect, Circle });
+story_enum!(Placeholder { Blur, Color, None });
+
+fn button_stories(registry: StoryRegistry) -> StoryRegistry {
+    let render = |props: &Args| {
+        let variant = props.choice::<ButtonVariant>("variant");
+        let size = props.choice::<ButtonSize>("size");
+        let label = props.text("children");
+        view! {
+            <Button
+                variant=variant
+                size=size
+                disabled=props.flag("disabled")
+                loading=props.flag("loading")
+            >
+                {label}
+            </Button>
+        }
+        .into_any()
+    };
+    let controls = |story: Story, variant: ButtonVariant, loading: bool, label: &str| {
+        story
+            .children(label)
+            .choice("variant", variant)
+            .choice("size", ButtonSize::Medium)
+            .flag("disabled", false)
+            .flag("loading", loading)
+    };
+
+    registry
+        .story(controls(
+            Story::new("Button", "Primary", render).description("The main action of a view."),
+            ButtonVariant::Primary,
+            false,
+            "Launch",
+        ))
+        .story(controls(Story::new("Button", "Secondary", render), ButtonVariant::Secondary, false, "Cancel"))
+        .story(controls(Story::new("Button", "Text", render), ButtonVariant::Text, false, "Learn more"))
+        .story(
+            controls(Story::new("Button", "Loading", render), ButtonVariant::Primary, true, "Saving")
+                .description("Disabled with a spinner and `aria-busy` while work is in flight."),
+        )
+}
+
+fn typography_stories(registry: StoryRegistry) -> StoryRegistry {
+    registry
+        .story(
+            Story::new("Heading", "Levels", |props| {
+                let level = props.choice::<HeadingLevel>("level");
+                let text = props.text("children");
+                // `level` is read once by `Heading`, so rebuild it on change.
+                (move || view! { <Heading level=level.get()>{text}</Heading> }).into_any()
+            })
+            .children("Crystalline Neon")
+            .choice("level", HeadingLevel::H1),
+        )
+        .story(
+            Story::new("Text", "Body", |props| {
+                let size = props.choice::<TextSize>("size");
+                let tone = props.choice::<TextTone>("tone");
+                let text = props.text("children");
+                view! {
+                    <Text size=size tone=tone truncate=props.flag("truncate") class="max-w-md">
+                        {text}
+                    </Text>
+                }
+                .into_any()
+            })
+            .children("Memory flows through the graph as events, each one a fact that never changes.")
+            .choice("size", TextSize::Base)
+            .choice("tone", TextTone::Secondary)
+            .flag("truncate", false),
+        )
+}
+
+fn card_stories(registry: StoryRegistry) -> StoryRegistry {
+    registry.story(
+        Story::new("Card", "Variants", |props| {
+            let variant = props.choice::<CardVariant>("variant");
+            view! {
+                <Card variant=variant hoverable=props.flag("hoverable") class="max-w-sm">
+                    <CardHeader title="Event store" subtitle="JetStream" />
+                    <CardBody>"Every change is an immutable event, replayable at any time."</CardBody>
+                </Card>
+            }
+            .into_any()
+        })
+        .choice("variant", CardVariant::GlowEdge)
+        .flag("hoverable", false)
+        .body("<CardHeader title=\"Event store\" subtitle=\"JetStream\" />\n<CardBody>\"Every change is an immutable event.\"</CardBody>"),
+    )
+}
+
+fn icon_stories(registry: StoryRegistry) -> StoryRegistry {
+    registry.story(
+        Story::new("Icon", "Gallery", |props| {
+            let name = props.choice::<IconName>("name");
+            let size = props.choice::<IconSize>("size");
+            let title = props.text("title");
+            // Icons take plain props, so rebuild on change.
+            (move || {
+                let title = title.get();
+                let icon = if title.is_empty() {
+                    view! { <Icon name=name.get() size=size.get() /> }.into_any()
+                } else {
+                    view! { <Icon name=name.get() size=size.get() title=title /> }.into_any()
+                };
+                view! { <span class="text-accent">{icon}</span> }
+            })
+            .into_any()
+        })
+        .choice("name", IconName::Science)
+        .choice("size", IconSize::ExtraLarge)
+        .text("title", ""),
+    )
+}
+
+fn overlay_stories(registry: StoryRegistry) -> StoryRegistry {
+    registry
+        .story(
+            Story::new("Tooltip", "On a button", |props| {
+                view! {
+                    <Tooltip content=props.text("content")>
+                        <Button variant=ButtonVariant::Secondary>"Hover or focus me"</Button>
+                    </Tooltip>
+                }
+                .into_any()
+            })
+            .text("content", "Copies the link to this page")
+            .body("<Button variant=ButtonVariant::Secondary>\"Hover or focus me\"</Button>"),
+        )
+        .story(
+            Story::new("DropdownMenu", "Items, options and submenus", |_| {
+                let grid = RwSignal::new(true);
+                let density = RwSignal::new("comfortable".to_string());
+                view! {
+                    <DropdownMenu trigger=|| "Options ▾">
+                        <MenuItem on_select=|| () shortcut="Ctrl+E">"Edit"</MenuItem>
+                        <MenuItem on_select=|| ()>"Duplicate"</MenuItem>
+                        <MenuItem on_select=|| () disabled=true>"Archive"</MenuItem>
+                        <MenuSeparator />
+                        <MenuCheckboxItem checked=grid>"Show grid"</MenuCheckboxItem>
+                        <MenuRadioGroup value=density label="Density">
+                            <MenuRadioItem value="compact">"Compact"</MenuRadioItem>
+                            <MenuRadioItem value="comfortable">"Comfortable"</MenuRadioItem>
+                        </MenuRadioGroup>
+                        <MenuSeparator />
+                        <SubMenu label="Share">
+                            <MenuItem on_select=|| ()>"Copy link"</MenuItem>
+                            <MenuItem on_select=|| ()>"Email"</MenuItem>
+                        </SubMenu>
+                    </DropdownMenu>
+                }
+                .into_any()
+            })
+            .description("Arrow keys move, typing jumps to an item, Right and Left open and close the submenu.")
+            .code(|_| {
+                r#"<DropdownMenu trigger=|| "Options ▾">
+    <MenuItem on_select=edit shortcut="Ctrl+E">"Edit"</MenuItem>
+    <MenuSeparator />
+    <MenuCheckboxItem checked=grid>"Show grid"</MenuCheckboxItem>
+    <MenuRadioGroup value=density label="Density">
+        <MenuRadioItem value="compact">"Compact"</MenuRadioItem>
+        <MenuRadioItem value="comfortable">"Comfortable"</MenuRadioItem>
+    </MenuRadioGroup>
+    <SubMenu label="Share">
+        <MenuItem on_select=copy_link>"Copy link"</MenuItem>
+    </SubMenu>
+</DropdownMenu>"#
+                    .to_string()
+            }),
+        )
+}
+
+fn loading_stories(registry: StoryRegistry) -> StoryRegistry {
+    registry
+        .story(
+            Story::new("Spinner", "Sizes", |props| {
+                let size = props.choice::<SpinnerSize>("size");
+                (move || view! { <Spinner size=size.get() /> }).into_any()
+            })
+            .choice("size", SpinnerSize::Large),
+        )
+        .story(
+            Story::new("Progress", "Determinate", |props| {
+                let value = props.number("value");
+                let label = props.text("label");
+                let show_value = props.flag("show_value");
+                // Only `value` is reactive on `Progress`; rebuild for the rest.
+                (move || {
+                    view! {
+                        <Progress
+                            value=Signal::derive(move || Some(value.get()))
+                            label=label.get()
+                            show_value=show_value.get()
+                            class="w-full max-w-md"
+                        />
+                    }
+                })
+                .into_any()
+            })
+            .text("label", "Uploading")
+            .number("value", 60.0, 0.0, (0.0, 100.0, 1.0))
+            .flag("show_value", true)
+            .code(|props| {
+                let show_value = if props.flag("show_value").get() { " show_value=true" } else { "" };
+                format!(
+                    "<Progress value=Some({:.1}) label={:?}{} />",
+                    props.number("value").get(),
+                    props.text("label").get(),
+                    show_value
+                )
+            }),
+        )
+        .story(
+            Story::new("Progress", "Indeterminate", |props| {
+                let label = props.text("label");
+                (move || view! { <Progress label=label.get() class="w-full max-w-md" /> }).into_any()
+            })
+            .text("label", "Syncing events"),
+        )
+        .story(
+            Story::new("ProgressRing", "Determinate", |props| {
+                let value = props.number("value");
+                view! { <ProgressRing value=Signal::derive(move || Some(value.get())) label="Indexing" show_value=true size=64 /> }.into_any()
+            })
+            .number("value", 35.0, 0.0, (0.0, 100.0, 1.0))
+            .code(|props| {
+                format!("<ProgressRing value=Some({:.1}) label=\"Indexing\" show_value=true size=64 />", props.number("value").get())
+            }),
+        )
+        .story(
+            Story::new("Skeleton", "Shapes", |props| {
+                let shape = props.choice::<SkeletonShape>("shape");
+                let lines = props.number("lines");
+                (move || {
+                    view! {
+                        <div class="w-full max-w-md">
+                            <Skeleton shape=shape.get() lines=lines.get() as usize />
+                        </div>
+                    }
+                })
+                .into_any()
+            })
+            .choice("shape", SkeletonShape::Text)
+            .number("lines", 3.0, 1.0, (1.0, 8.0, 1.0)),
+        )
+}
+
+fn image_stories(registry: StoryRegistry) -> StoryRegistry {
+    registry.story(
+        Story::new("Image", "Responsive", |props| {
+            let src = props.text("src");
+            let alt = props.text("alt");
+            let placeholder = props.choice::<Placeholder>("placeholder");
+            (move || {
+                view! {
+                    <Image
+                        src=src.get()
+                        alt=alt.get()
+                        placeholder=placeholder.get()
+                        sizes="(min-width: 1024px) 28rem, 100vw"
+                        class="w-full max-w-md rounded-lg"
+                    />
+                }
+            })
+            .into_any()
+        })
+        .description("AVIF/WebP variants from `imagegen`, lazy loading and a reserved box.")
+        .text("src", "/assets/hands.png")
+        .text("alt", "Two hands reaching towards each other")
+        .choice("placeholder", Placeholder::Blur),
+    )
+}
+
+/// Every story, grouped by component.
+pub fn registry() -> StoryRegistry {
+    let registry = StoryRegistry::new();
+    let registry = button_stories(registry);
+    let registry = typography_stories(registry);
+    let registry = card_stories(registry);
+    let registry = icon_stories(registry);
+    let registry = overlay_stories(registry);
+    let registry = loading_stories(registry);
+    image_stories(registry)
+}
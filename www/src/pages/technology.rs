@@ -0,0 +1,59 @@
+use leptos::prelude::*;
+
+use crate::chunks::LazyChunk;
+use crate::components::*;
+use crate::components::typography::{Heading, HeadingLevel, Text, TextSize, TextTone};
+
+const GRAPH_FORMATS: [(&str, &str); 3] = [
+    ("Petgraph", "In-memory graph structures and algorithms in pure Rust."),
+    ("Mermaid", "Text-first diagrams rendered straight from documentation."),
+    ("Cypher", "Declarative queries against graph databases."),
+];
+
+/// Technology Stack page (/technology). The graph is the `technology` chunk,
+/// fetched when the page first renders or its nav link is hovered.
+#[component]
+pub fn Technology() -> impl IntoView {
+    view! {
+        <div class="max-w-5xl mx-auto space-y-12">
+            <section class="space-y-4">
+                <Heading level=HeadingLevel::H1>"Technology Stack"</Heading>
+                <Text size=TextSize::Xl tone=TextTone::Secondary>
+                    "Rust end to end: the Memory Bank runs in WebAssembly, talks over NATS and models knowledge as graphs."
+                </Text>
+            </section>
+
+            <section class="space-y-6">
+                <Heading level=HeadingLevel::H2>"Graph Visualization"</Heading>
+                <Card variant=CardVariant::Glass>
+                    <LazyChunk chunk="technology" />
+                </Card>
+                <div class="grid gap-6 md:grid-cols-3">
+                    {GRAPH_FORMATS
+                        .into_iter()
+                        .map(|(name, summary)| view! {
+                            <Card variant=CardVariant::GlowEdge>
+                                <CardHeader title=name />
+                                <CardBody class="text-sm">{summary}</CardBody>
+                            </Card>
+                        })
+                        .collect_view()}
+                </div>
+            </section>
+
+            <section class="space-y-4">
+                <Heading level=HeadingLevel::H2>"Rust Ecosystem"</Heading>
+                <Text>
+                    "Leptos renders the interface, Trunk bundles it, and Nix pins the toolchain so every build is reproducible."
+                </Text>
+            </section>
+
+            <section class="space-y-4">
+                <Heading level=HeadingLevel::H2>"AI Capabilities"</Heading>
+                <Text>
+                    "Agents share context through the Memory Bank, turning conversations into durable, queryable knowledge."
+                </Text>
+            </section>
+        </div>
+    }
+}
@@ -0,0 +1,233 @@
+//! Legacy URL redirect table.
+//!
+//! The old thecowboy.ai site is still linked from around the web. Every entry
+//! below maps one of its paths (or a pattern of paths) onto a route of the new
+//! site. The router consults this table before falling back to `NotFound`, and
+//! the `redirect_stubs` binary turns the exact-path entries into static HTML
+//! stubs so GitHub Pages can answer them without booting the app.
+//!
+//! Pattern syntax, matched segment by segment:
+//! - `literal` matches itself (case-insensitive)
+//! - `:name` captures exactly one segment
+//! - `*name` captures the remaining segments (may be empty); must come last
+//! - `*` is an anonymous wildcard for the remaining segments
+//!
+//! Targets may reference captures with the same `:name` / `*name` syntax.
+
+/// Whether a redirect is expected to live forever or may be revisited later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedirectKind {
+    /// The old URL is gone for good (HTTP 301 semantics).
+    Permanent,
+    /// The old URL points somewhere provisional (HTTP 302 semantics).
+    Temporary,
+}
+
+impl RedirectKind {
+    pub fn is_permanent(&self) -> bool {
+        matches!(self, RedirectKind::Permanent)
+    }
+}
+
+/// A single entry of the redirect table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Redirect {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub kind: RedirectKind,
+}
+
+impl Redirect {
+    pub const fn permanent(from: &'static str, to: &'static str) -> Self {
+        Self { from, to, kind: RedirectKind::Permanent }
+    }
+
+    pub const fn temporary(from: &'static str, to: &'static str) -> Self {
+        Self { from, to, kind: RedirectKind::Temporary }
+    }
+
+    /// True when the pattern has no captures or wildcards and can therefore be
+    /// emitted as a static stub page.
+    pub fn is_exact(&self) -> bool {
+        segments(self.from).all(|segment| !segment.starts_with(':') && !segment.starts_with('*'))
+    }
+}
+
+/// Paths the router in `lib.rs` serves, in the pattern syntax above. Keep
+/// in sync with `AppRoutes`; every redirect target must land on one of them.
+pub const ROUTES: &[&str] = &["/", "/showcase", "/technology"];
+
+/// Old site paths and where they live now. First match wins, so list specific
+/// patterns before general ones. Old sections without a page here yet (about,
+/// contact, blog, docs, legal) are left out, so they 404 rather than land
+/// somewhere unrelated; add them when their routes exist.
+pub const REDIRECTS: &[Redirect] = &[
+    Redirect::permanent("/index.html", "/"),
+    Redirect::permanent("/home", "/"),
+    Redirect::permanent("/tech", "/technology"),
+    Redirect::permanent("/tech/*", "/technology"),
+    Redirect::temporary("/graph/:format", "/technology"),
+    Redirect::permanent("/demos", "/showcase"),
+    Redirect::temporary("/demo/*", "/showcase"),
+];
+
+/// The outcome of matching a path against the redirect table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedRedirect {
+    pub target: String,
+    pub kind: RedirectKind,
+}
+
+/// Look up `path` (optionally carrying a `?query` and/or `#hash`) in [`REDIRECTS`].
+pub fn resolve(path: &str) -> Option<ResolvedRedirect> {
+    resolve_in(REDIRECTS, path)
+}
+
+/// Look up `path` in an arbitrary table. The query string and hash of the
+/// incoming path are carried over to the target unless the target sets its own.
+pub fn resolve_in(table: &[Redirect], path: &str) -> Option<ResolvedRedirect> {
+    let (path, suffix) = split_suffix(path);
+
+    table.iter().find_map(|redirect| {
+        let captures = match_pattern(redirect.from, path)?;
+        let mut target = substitute(redirect.to, &captures);
+        if !redirect.to.contains(['?', '#']) {
+            target.push_str(suffix);
+        }
+        Some(ResolvedRedirect { target, kind: redirect.kind })
+    })
+}
+
+/// Match `path` against `pattern`, returning the captured values by name.
+/// Anonymous wildcards are stored under the empty name.
+pub fn match_pattern<'a>(pattern: &'a str, path: &'a str) -> Option<Vec<(&'a str, String)>> {
+    let mut captures = Vec::new();
+    let mut path_segments = segments(path).peekable();
+
+    for segment in segments(pattern) {
+        if let Some(name) = segment.strip_prefix('*') {
+            let rest: Vec<&str> = path_segments.by_ref().collect();
+            captures.push((name, rest.join("/")));
+            return Some(captures);
+        }
+
+        let value = path_segments.next()?;
+        if let Some(name) = segment.strip_prefix(':') {
+            captures.push((name, value.to_string()));
+        } else if !segment.eq_ignore_ascii_case(value) {
+            return None;
+        }
+    }
+
+    path_segments.peek().is_none().then_some(captures)
+}
+
+/// Replace `:name` and `*name` references in `target` with captured values.
+fn substitute(target: &str, captures: &[(&str, String)]) -> String {
+    let mut resolved = String::with_capacity(target.len());
+
+    for segment in segments(target) {
+        let value = match segment.strip_prefix(':').or_else(|| segment.strip_prefix('*')) {
+            Some(name) => captures
+                .iter()
+                .find(|(captured, _)| *captured == name)
+                .map(|(_, value)| value.as_str())
+                .unwrap_or_default(),
+            None => segment,
+        };
+        if !value.is_empty() {
+            resolved.push('/');
+            resolved.push_str(value);
+        }
+    }
+
+    if resolved.is_empty() {
+        resolved.push('/');
+    }
+    resolved
+}
+
+/// Split `/path?query#hash` into `/path` and `?query#hash`.
+fn split_suffix(path: &str) -> (&str, &str) {
+    match path.find(['?', '#']) {
+        Some(index) => path.split_at(index),
+        None => (path, ""),
+    }
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_target_is_a_route() {
+        for redirect in REDIRECTS {
+            // Stand-in values for any captures the target refers to.
+            let captures: Vec<(&str, String)> = segments(redirect.from)
+                .filter_map(|segment| segment.strip_prefix(':').or_else(|| segment.strip_prefix('*')))
+                .map(|name| (name, "x".to_string()))
+                .collect();
+            let target = substitute(redirect.to, &captures);
+            assert!(
+                ROUTES.iter().any(|route| match_pattern(route, &target).is_some()),
+                "{} redirects to {}, which isn't routed",
+                redirect.from,
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn literals_match_whole_paths_case_insensitively() {
+        assert_eq!(match_pattern("/about/team", "/About/TEAM/"), Some(vec![]));
+        assert_eq!(match_pattern("/about", "/about/team"), None);
+        assert_eq!(match_pattern("/about/team", "/about"), None);
+        assert_eq!(match_pattern("/", "/"), Some(vec![]));
+    }
+
+    #[test]
+    fn params_capture_one_segment() {
+        assert_eq!(
+            match_pattern("/blog/:year/:slug", "/blog/2021/hello"),
+            Some(vec![("year", "2021".to_string()), ("slug", "hello".to_string())])
+        );
+        assert_eq!(match_pattern("/blog/:slug", "/blog"), None);
+        assert_eq!(match_pattern("/blog/:slug", "/blog/a/b"), None);
+    }
+
+    #[test]
+    fn wildcards_capture_the_rest() {
+        assert_eq!(match_pattern("/docs/*rest", "/docs/a/b/c"), Some(vec![("rest", "a/b/c".to_string())]));
+        assert_eq!(match_pattern("/docs/*rest", "/docs"), Some(vec![("rest", String::new())]));
+        assert_eq!(match_pattern("/demo/*", "/demo/x"), Some(vec![("", "x".to_string())]));
+        assert_eq!(match_pattern("/docs/*rest", "/other/a"), None);
+    }
+
+    #[test]
+    fn substitute_fills_captures_and_drops_empty_segments() {
+        let captures = [("slug", "hello".to_string()), ("rest", "a/b".to_string()), ("empty", String::new())];
+        assert_eq!(substitute("/blog/:slug", &captures), "/blog/hello");
+        assert_eq!(substitute("/docs/*rest", &captures), "/docs/a/b");
+        assert_eq!(substitute("/docs/*empty", &captures), "/docs");
+        assert_eq!(substitute("/:missing", &captures), "/");
+    }
+
+    #[test]
+    fn resolve_keeps_query_and_hash_unless_the_target_sets_them() {
+        let table = [
+            Redirect::permanent("/old/:slug", "/new/:slug"),
+            Redirect::temporary("/pinned", "/new?tab=a"),
+        ];
+        assert_eq!(
+            resolve_in(&table, "/old/x?q=1#top"),
+            Some(ResolvedRedirect { target: "/new/x?q=1#top".to_string(), kind: RedirectKind::Permanent })
+        );
+        assert_eq!(resolve_in(&table, "/pinned?q=1").map(|resolved| resolved.target), Some("/new?tab=a".to_string()));
+        assert_eq!(resolve_in(&table, "/missing"), None);
+        assert_eq!(resolve("/TECH/graphs").map(|resolved| resolved.target), Some("/technology".to_string()));
+    }
+}
@@ -1,6 +1,6 @@
 /** @type {import('tailwindcss').Config} */
 module.exports = {
-  content: ["*.html", "./src/**/*.rs"],
+  content: ["*.html", "./src/**/*.rs", "./chunks/*/src/**/*.rs"],
   darkMode: ['class', '[data-theme="dark"]'],
   theme: {
     extend: {